@@ -56,6 +56,14 @@ F5: Square
 F6: Square
 F7: Square
 F8: Square
+FILE_A: Bitboard
+FILE_B: Bitboard
+FILE_C: Bitboard
+FILE_D: Bitboard
+FILE_E: Bitboard
+FILE_F: Bitboard
+FILE_G: Bitboard
+FILE_H: Bitboard
 G1: Square
 G2: Square
 G3: Square
@@ -77,7 +85,16 @@ KNIGHT: PieceType
 PAWN: PieceType
 PIECES: builtins.list[PieceType]
 QUEEN: PieceType
+RANK_1: Bitboard
+RANK_2: Bitboard
+RANK_3: Bitboard
+RANK_4: Bitboard
+RANK_5: Bitboard
+RANK_6: Bitboard
+RANK_7: Bitboard
+RANK_8: Bitboard
 ROOK: PieceType
+SQUARES: builtins.list[Square]
 WHITE: Color
 class Bitboard:
     r"""
@@ -106,6 +123,38 @@ class Bitboard:
         r"""
         Convert the Bitboard to a square.
         This grabs the least-significant square.
+        Raises a `ValueError` if the Bitboard is empty (use `lsb` to get None instead).
+        
+        ```python
+        >>> rust_chess.Bitboard(rust_chess.E4).to_square()
+        e4
+        >>> rust_chess.Bitboard(0).to_square()
+        Traceback (most recent call last):
+        ...
+        ValueError: Bitboard is empty
+        ```
+        """
+    def lsb(self) -> typing.Optional[Square]:
+        r"""
+        Get the least-significant square of the Bitboard, otherwise None if it is empty.
+        
+        ```python
+        >>> (rust_chess.C3.to_bitboard() | rust_chess.F6.to_bitboard()).lsb()
+        c3
+        >>> rust_chess.Bitboard(0).lsb()
+        
+        ```
+        """
+    def msb(self) -> typing.Optional[Square]:
+        r"""
+        Get the most-significant square of the Bitboard, otherwise None if it is empty.
+        
+        ```python
+        >>> (rust_chess.C3.to_bitboard() | rust_chess.F6.to_bitboard()).msb()
+        f6
+        >>> rust_chess.Bitboard(0).msb()
+        
+        ```
         """
     def to_uint(self) -> builtins.int:
         r"""
@@ -139,15 +188,89 @@ class Bitboard:
         r"""
         Count the number of squares in the Bitboard
         """
+    def __len__(self) -> builtins.int:
+        r"""
+        Get the number of squares in the Bitboard.
+        
+        ```python
+        >>> len(rust_chess.Bitboard(0))
+        0
+        >>> len(rust_chess.Bitboard(0xFF))
+        8
+        ```
+        """
+    def __bool__(self) -> builtins.bool:
+        r"""
+        Check if the Bitboard has any squares.
+        
+        ```python
+        >>> bool(rust_chess.Bitboard(0))
+        False
+        >>> bool(rust_chess.Bitboard(rust_chess.E4))
+        True
+        ```
+        """
+    def __contains__(self, square:Square) -> builtins.bool:
+        r"""
+        Check if a square is in the Bitboard.
+        
+        ```python
+        >>> rust_chess.A1 in rust_chess.Bitboard.from_square(rust_chess.A1)
+        True
+        >>> rust_chess.A2 in rust_chess.Bitboard.from_square(rust_chess.A1)
+        False
+        ```
+        """
     def flip_vertical(self) -> Bitboard:
         r"""
         Flip a bitboard vertically.
         View it from the opponent's perspective.
         Useful for operations that rely on symmetry, like piece-square tables.
         """
+    def mirror_horizontal(self) -> Bitboard:
+        r"""
+        Mirror a bitboard horizontally (swap the a-file and h-file, etc.).
+        
+        ```python
+        >>> list((rust_chess.B1.to_bitboard() | rust_chess.C6.to_bitboard()).mirror_horizontal())
+        [g1, f6]
+        ```
+        """
+    def rotate_180(self) -> Bitboard:
+        r"""
+        Rotate a bitboard by 180 degrees (flip it both vertically and horizontally).
+        
+        ```python
+        >>> list((rust_chess.B1.to_bitboard() | rust_chess.C6.to_bitboard()).rotate_180())
+        [f3, g8]
+        >>> bitboard = rust_chess.Bitboard(0x1234_5678_9ABC_DEF0)
+        >>> bitboard.rotate_180().rotate_180() == bitboard
+        True
+        ```
+        """
+    def shift_dir(self, direction:Direction) -> Bitboard:
+        r"""
+        Shift the Bitboard one square in a direction.
+        Squares shifted off the edge of the board are removed (they don't wrap around).
+        
+        ```python
+        >>> list(rust_chess.Bitboard(rust_chess.E4).shift_dir(rust_chess.Direction.NE))
+        [f5]
+        >>> rust_chess.Bitboard(rust_chess.H4).shift_dir(rust_chess.Direction.EAST).popcnt()
+        0
+        ```
+        """
     def __iter__(self) -> Bitboard:
         r"""
-        Return an iterator of the bitboard
+        Return an iterator of the bitboard.
+        Iterates over a copy, so the bitboard itself (e.g. a module constant) is not emptied.
+        
+        ```python
+        >>> len(list(rust_chess.RANK_1))
+        8
+        >>> rust_chess.RANK_1.popcnt()
+        8
+        ```
         """
     def __next__(self) -> typing.Optional[Square]:
         r"""
@@ -239,10 +362,15 @@ class Board:
     halfmove_clock: builtins.int
     r"""
     Get the halfmove clock.
+    Stops increasing at 255 instead of overflowing.
     
     ```python
     >>> rust_chess.Board().halfmove_clock
     0
+    >>> board = rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 255 1")
+    >>> board.make_move(rust_chess.Move("e1e2"))
+    >>> board.halfmove_clock
+    255
     ```
     """
     fullmove_number: builtins.int
@@ -254,6 +382,15 @@ class Board:
     1
     ```
     """
+    chess960: builtins.bool
+    r"""
+    Check if the board is in Chess960 (Fischer Random) mode.
+    
+    ```python
+    >>> rust_chess.Board().chess960
+    False
+    ```
+    """
     turn: Color
     r"""
     Get the current player to move.
@@ -279,9 +416,10 @@ class Board:
     f5
     ```
     """
-    def __new__(cls, fen:typing.Optional[builtins.str]=None) -> Board:
+    def __new__(cls, fen:typing.Optional[builtins.str]=None, chess960:builtins.bool=False) -> Board:
         r"""
         Create a new board from a FEN string, otherwise default to the starting position.
+        If `chess960` is True, the board is in Chess960 mode (see `from_fen`).
         
         ```python
         >>> rust_chess.Board()
@@ -317,209 +455,1737 @@ class Board:
         rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
         ```
         """
-    @staticmethod
-    def from_fen(fen:builtins.str) -> Board:
+    def has_kingside_castle_rights(self, color:Color) -> builtins.bool:
         r"""
-        Create a new board from a FEN string.
+        Check if a color has kingside castling rights.
+        Doesn't check if castling is currently possible.
         
         ```python
-        >>> rust_chess.Board.from_fen("rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2")
-        rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2
+        >>> rust_chess.Board().has_kingside_castle_rights(rust_chess.BLACK)
+        True
+        >>> rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w Qkq - 0 1").has_kingside_castle_rights(rust_chess.WHITE)
+        False
         ```
         """
-    def get_piece_type_on(self, square:Square) -> typing.Optional[PieceType]:
+    def has_queenside_castle_rights(self, color:Color) -> builtins.bool:
         r"""
-        Get the piece type on a square, otherwise None.
-        Different than `get_piece_on` because it returns the piece type, which does not include color.
+        Check if a color has queenside castling rights.
+        Doesn't check if castling is currently possible.
         
         ```python
-        >>> rust_chess.Board().get_piece_type_on(rust_chess.A1)
-        R
-        >>> rust_chess.Board().get_piece_type_on(rust_chess.E8)
-        K
+        >>> rust_chess.Board().has_queenside_castle_rights(rust_chess.WHITE)
+        True
+        >>> rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w Kkq - 0 1").has_queenside_castle_rights(rust_chess.WHITE)
+        False
         ```
         """
-    def get_color_on(self, square:Square) -> typing.Optional[Color]:
+    def castle_rights(self, color:Color) -> builtins.str:
         r"""
-        Get the color of the piece on a square, otherwise None.
+        Get the castling rights of a color as in a FEN string ("KQ", "K", "Q", or "-" for white, lowercase for black).
         
         ```python
-        >>> rust_chess.Board().get_color_on(rust_chess.A1)
+        >>> board = rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w Kkq - 0 1")
+        >>> board.castle_rights(rust_chess.WHITE)
+        'K'
+        >>> board.castle_rights(rust_chess.BLACK)
+        'kq'
+        >>> rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 0 1").castle_rights(rust_chess.WHITE)
+        '-'
+        ```
+        """
+    def lost_castling_rights(self, other:Board) -> builtins.list[builtins.str]:
+        r"""
+        Get the castling rights ("K", "Q", "k", or "q") that this board has but another board doesn't.
+        Useful for debugging how castling rights change across moves.
+        
+        ```python
+        >>> board = rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1")
+        >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("h1g1")))
+        ['K']
+        >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("a1a8")))
+        ['Q', 'q']
+        >>> board = rust_chess.Board("bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1", chess960=True)
+        >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("f1e1")))
+        ['Q']
+        >>> board = rust_chess.Board("bbqnnrkr/pppppppp/8/8/8/8/PPPPPP1P/BBQNNRKR w HFhf - 0 1", chess960=True)
+        >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("g1g2")))
+        ['K', 'Q']
+        ```
+        """
+    def to_planes(self) -> builtins.list[builtins.list[builtins.list[builtins.int]]]:
+        r"""
+        Get the pieces as a (12, 8, 8) nested list of 0s and 1s, indexed as `planes[plane][rank][file]`.
+        The planes are white pawn, knight, bishop, rook, queen, king, then the same for black.
+        Ranks and files start from 0, so `planes[0][1][4]` is a white pawn on e2.
+        Can be converted with `numpy.array(board.to_planes())`.
+        
+        ```python
+        >>> planes = rust_chess.Board().to_planes()
+        >>> planes[0][1]
+        [1, 1, 1, 1, 1, 1, 1, 1]
+        >>> sum(map(sum, planes[0] + planes[6]))
+        16
+        >>> planes[11][7][4] # Black king on e8
+        1
+        ```
+        """
+    def to_array(self) -> builtins.list[builtins.int]:
+        r"""
+        Get the pieces as a list of 64 integers, indexed by square (a1 = 0, h8 = 63).
+        Empty squares are 0, white pieces are 1 to 6 (pawn, knight, bishop, rook, queen, king), and black pieces are -1 to -6.
+        
+        ```python
+        >>> array = rust_chess.Board().to_array()
+        >>> array[0] # White rook on a1
+        4
+        >>> array[60] # Black king on e8
+        -6
+        >>> array[16:48] == [0] * 32
         True
-        >>> print(rust_chess.Board().get_color_on(rust_chess.A1))
-        WHITE
-        >>> rust_chess.Board().get_color_on(rust_chess.E8)
-        False
+        ```
         """
-    def get_piece_on(self, square:Square) -> typing.Optional[Piece]:
+    def to_input_tensor(self) -> builtins.list[builtins.float]:
         r"""
-        Get the piece on a square (color-inclusive), otherwise None.
-        Different than `get_piece_on` because it returns the piece, which includes color.
+        Get the input features for a neural network as a flat list of 19 * 8 * 8 = 1216 floats,
+        indexed as `tensor[channel * 64 + rank * 8 + file]` (reshape to (19, 8, 8)).
+        The channels are:
+        - 0-11: the pieces, in the same order as `to_planes`
+        - 12: all ones if white is to move, otherwise all zeros
+        - 13-16: all ones for each castling right (white kingside, white queenside, black kingside, black queenside)
+        - 17: a one on the en passant target square (e.g. e3), if there is one
+        - 18: the halfmove clock divided by 100 (capped at 1)
         
         ```python
-        >>> rust_chess.Board().get_piece_on(rust_chess.A1)
-        R
-        >>> rust_chess.Board().get_piece_on(rust_chess.E8)
-        k
+        >>> tensor = rust_chess.Board().to_input_tensor()
+        >>> len(tensor)
+        1216
+        >>> all(value == 1.0 for value in tensor[12 * 64:17 * 64]) # Side to move and castling rights
+        True
+        >>> sum(tensor[17 * 64:])
+        0.0
         ```
         """
-    def get_king_square(self, color:Color) -> Square:
+    def as_tuple(self) -> tuple[builtins.str, builtins.bool, builtins.str, typing.Optional[builtins.int]]:
         r"""
-        Get the king square of a certain color
+        Get a hashable tuple snapshot of the position:
+        (board FEN, True if white to move, castling rights FEN, en passant target square index or None).
+        Ignores the halfmove clock and fullmove number, so transpositions produce equal tuples.
+        
+        ```python
+        >>> rust_chess.Board().as_tuple()
+        ('rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR', True, 'KQkq', None)
+        >>> seen = {rust_chess.Board().as_tuple(): 0}
+        >>> rust_chess.Board().as_tuple() in seen
+        True
+        >>> rust_chess.Board("bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w Hh - 0 1", chess960=True).as_tuple()
+        ('bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR', True, 'Kk', None)
+        >>> rust_chess.Board("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3").as_tuple()[3] == rust_chess.E3
+        True
+        ```
         """
-    def is_zeroing(self, chess_move:Move) -> builtins.bool:
+    def relative_material(self) -> builtins.int:
         r"""
-        Check if a move is a capture or a pawn move.
-        Doesn't check legality.
+        Get the material balance in centipawns from the side to move's perspective.
+        Positive if the side to move is ahead in material.
+        Uses the default piece values (pawn 100, knight 300, bishop 300, rook 500, queen 900).
+        
+        ```python
+        >>> rust_chess.Board().relative_material()
+        0
+        >>> rust_chess.Board("rnbqkbnr/ppp1pppp/8/3P4/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2").relative_material()
+        -100
+        ```
         """
-    def is_legal_move(self, chess_move:Move) -> builtins.bool:
+    def material_balance(self, values:typing.Optional[typing.Mapping[PieceType, builtins.int]]=None) -> builtins.int:
         r"""
-        Check if the move is legal (supposedly very slow according to the chess crate).
-        Use this function for moves not generated by the move generator.
-        `is_legal_quick` is faster for moves generated by the move generator.
+        Get the material balance in centipawns from white's perspective (white material minus black material).
+        Uses the default piece values (pawn 100, knight 300, bishop 300, rook 500, queen 900, king 0)
+        unless they are replaced by the given values.
         
         ```python
-        >>> move = rust_chess.Move("e2e4")
-        >>> rust_chess.Board().is_legal_move(move)
+        >>> rust_chess.Board().material_balance()
+        0
+        >>> board = rust_chess.Board("rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+        >>> board.material_balance()
+        900
+        >>> board.material_balance({rust_chess.QUEEN: 950})
+        950
+        ```
+        """
+    def get_hash(self) -> builtins.int:
+        r"""
+        Get the Zobrist hash of the board's position.
+        Useful as a key for transposition tables.
+        The halfmove clock and fullmove number are not included.
+        
+        ```python
+        >>> rust_chess.Board().get_hash() == rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9").get_hash()
         True
-        >>> move2 = rust_chess.Move("e2e5")
-        >>> rust_chess.Board().is_legal_move(move2)
+        >>> rust_chess.Board().get_hash() == rust_chess.Board().make_move_new(rust_chess.Move("e2e4")).get_hash()
         False
         ```
         """
-    def make_null_move_new(self) -> typing.Optional[Board]:
+    def material_key(self) -> builtins.int:
         r"""
-        Make a null move onto a new board.
-        Returns None if the current player is in check.
+        Get the material key of the board.
+        The key only depends on the number of pieces of each color and type (not their placement),
+        so it can be used to index endgame tables.
+        Each piece count is packed into 4 bits (white pawns in the lowest bits, black kings in the highest),
+        so different material always has a different key.
+        
+        ```python
+        >>> kqkr1 = rust_chess.Board("3rk3/8/8/8/8/8/8/3QK3 w - - 0 1")
+        >>> kqkr2 = rust_chess.Board("4k3/8/2r5/8/8/5Q2/8/K7 b - - 0 1")
+        >>> kqkr1.material_key() == kqkr2.material_key()
+        True
+        >>> kqkr1.material_key() == rust_chess.Board("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").material_key()
+        False
+        ```
         """
-    def make_move_new(self, chess_move:Move, check_legality:builtins.bool=False) -> Board:
+    def __hash__(self) -> builtins.int:
         r"""
-        Make a move onto a new board
+        Get the hash of the board's position using the Zobrist hash.
+        The halfmove clock and fullmove number are not included.
+        
+        ```python
+        >>> board1 = rust_chess.Board()
+        >>> board2 = rust_chess.Board()
+        >>> for uci in ["g1f3", "g8f6", "b1c3"]:
+        ...     board1.make_move(rust_chess.Move(uci))
+        >>> for uci in ["b1c3", "g8f6", "g1f3"]:
+        ...     board2.make_move(rust_chess.Move(uci))
+        >>> hash(board1) == hash(board2)
+        True
+        ```
         """
-    def make_move(self, chess_move:Move, check_legality:builtins.bool=False) -> None:
+    def __eq__(self, other:typing.Any) -> builtins.bool:
         r"""
-        Make a move on the current board
+        Compare the board's position to another board.
+        Compares the piece placement, side to move, castling rights, and en passant square.
+        The halfmove clock and fullmove number are not compared.
+        
+        ```python
+        >>> rust_chess.Board() == rust_chess.Board()
+        True
+        >>> rust_chess.Board() == rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9")
+        True
+        >>> rust_chess.Board() == rust_chess.Board().make_move_new(rust_chess.Move("e2e4"))
+        False
+        ```
         """
-    def get_pinned_bitboard(self) -> Bitboard:
+    def equals(self, other:Board, include_counters:builtins.bool=False) -> builtins.bool:
         r"""
-        Get the bitboard of the side to move's pinned pieces
+        Compare the board to another board.
+        Compares only the position by default (same as `==`), which is what counts for repetitions.
+        If `include_counters` is True, the halfmove clock and fullmove number must also be equal.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> other = rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9")
+        >>> board.equals(other)
+        True
+        >>> board.equals(other, include_counters=True)
+        False
+        >>> board.equals(rust_chess.Board(), include_counters=True)
+        True
+        ```
         """
-    def get_checkers_bitboard(self) -> Bitboard:
+    def copy(self) -> Board:
         r"""
-        Get the bitboard of the pieces putting the side to move in check
+        Create a copy of the board.
+        The copy is independent of the original and has a fresh move generator.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board_copy = board.copy()
+        >>> board_copy.make_move(rust_chess.Move("e2e4"))
+        >>> board
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+        >>> moves = board.generate_legal_moves()
+        >>> _ = next(moves), next(moves)
+        >>> len(moves), len(board.copy().generate_legal_moves())
+        (18, 20)
+        ```
         """
-    def get_all_bitboard(self) -> Bitboard:
+    def __copy__(self) -> Board:
         r"""
-        Get the bitboard of all the pieces
+        Create a copy of the board (used by `copy.copy`).
+        
+        ```python
+        >>> import copy
+        >>> copy.copy(rust_chess.Board())
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+        ```
         """
-    def get_color_bitboard(self, color:Color) -> Bitboard:
+    def __deepcopy__(self, _memo:typing.Any) -> Board:
         r"""
-        Get the bitboard of all the pieces of a certain color
+        Create a copy of the board (used by `copy.deepcopy`).
+        
+        ```python
+        >>> import copy
+        >>> copy.deepcopy(rust_chess.Board())
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+        ```
         """
-    def get_piece_type_bitboard(self, piece_type:PieceType) -> Bitboard:
+    def __getstate__(self) -> tuple[builtins.str, builtins.list[Move], builtins.bool]:
         r"""
-        Get the bitboard of all the pieces of a certain type
+        Get the state of the board for pickling.
+        The state is the FEN string of the position before the first move on the move stack
+        (including the move counters), the moves on the move stack, and whether the board is in Chess960 mode.
+        
+        ```python
+        >>> import pickle
+        >>> pickle.loads(pickle.dumps(rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9")))
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9
+        ```
         """
-    def get_piece_bitboard(self, piece:Piece) -> Bitboard:
+    def __setstate__(self, state:tuple[builtins.str, typing.Sequence[Move], builtins.bool]) -> None:
         r"""
-        Get the bitboard of all the pieces of a certain color and type
+        Restore the state of the board when unpickling.
+        Replays the moves from the starting FEN string to rebuild the move stack.
         """
-    def remove_move(self, chess_move:Move) -> None:
+    def to_pgn(self, headers:typing.Optional[typing.Mapping[builtins.str, builtins.str]]=None) -> builtins.str:
         r"""
-        Remove a move from the move generator.
-        Prevents the move from being generated.
-        Useful if you already have a certain move and don't need to generate it again.
+        Export the game played on the board as a PGN string.
+        Uses the seven tag roster ("?" for unknown values) and the result of the game ("*" if ongoing).
+        Given headers replace the defaults, and other headers are added after the roster in sorted order.
+        Adds the `SetUp` and `FEN` headers if the game didn't start from the starting position,
+        and the `Variant` header in Chess960 mode.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> for uci in ["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"]:
+        ...     board.push(rust_chess.Move(uci))
+        >>> print(board.to_pgn({"White": "Alice", "Black": "Bob"}))
+        [Event "?"]
+        [Site "?"]
+        [Date "????.??.??"]
+        [Round "?"]
+        [White "Alice"]
+        [Black "Bob"]
+        [Result "1-0"]
+        <BLANKLINE>
+        1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0
+        ```
         """
-    def reset_move_generator(self) -> None:
+    @staticmethod
+    def from_pgn(pgn:builtins.str) -> Board:
         r"""
-        Reset the move generator for the current board
+        Create a new board by replaying the moves of a PGN string.
+        Starts from the `FEN` header if there is one, otherwise the starting position.
+        The board is in Chess960 mode if the `Variant` header is "Chess960" (or "Fischerandom").
+        Comments, NAGs (e.g. "$1"), and variations are ignored, and only the first game is read.
+        Raises a `ValueError` at the first invalid or illegal move.
+        
+        ```python
+        >>> board = rust_chess.Board.from_pgn('''
+        ... [Event "?"]
+        ... [Result "1-0"]
+        ...
+        ... 1. e4 e5 2. Bc4 {Aiming at f7} Nc6 3. Qh5 Nf6?? (3... g6 4. Qf3) 4. Qxf7# 1-0
+        ... ''')
+        >>> board
+        r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4
+        >>> board.peek()
+        Move(h5, f7, None)
+        >>> rust_chess.Board.from_pgn("1. e4 e5 2. Ke3")
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid or illegal move in PGN at 2. Ke3
+        ```
         """
-    def next_move(self) -> typing.Optional[Move]:
+    @staticmethod
+    def from_epd(epd:builtins.str) -> tuple[Board, builtins.dict[builtins.str, builtins.str]]:
         r"""
-        Get the next remaining move of the generator.
-        Updates the move generator to the next move.
-        Unless the mask is set, this will return the next legal move by default.
+        Create a new board from an EPD string, and get its operations (e.g. "bm", "id") as a dictionary.
+        The position only has the first four FEN fields, so the move counters are taken from the
+        "hmvc" and "fmvn" operations if they exist (otherwise 0 and 1).
+        Quotes around operands are removed, and operations without operands have empty strings.
+        
+        ```python
+        >>> board, operations = rust_chess.Board.from_epd('2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - bm Qg6; id "WAC.001";')
+        >>> board
+        2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - 0 1
+        >>> operations["id"]
+        'WAC.001'
+        >>> operations["bm"]
+        'Qg6'
+        ```
         """
-    def generate_legal_moves(self) -> MoveGenerator:
+    def to_epd(self, operations:typing.Optional[typing.Mapping[builtins.str, builtins.str]]=None) -> builtins.str:
         r"""
-        Generate the next remaining legal moves for the current board.
-        Exhausts the move generator if fully iterated over.
-        Updates the move generator.
+        Get the EPD string of the board: the first four FEN fields, then any operations sorted by opcode.
+        Operands of the "id", "c0"-"c9", and "v0"-"v9" opcodes are quoted.
+        
+        ```python
+        >>> board = rust_chess.Board("2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - 0 1")
+        >>> epd = board.to_epd({"id": "WAC.001", "bm": "Qg6"})
+        >>> epd
+        '2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - bm Qg6; id "WAC.001";'
+        >>> rust_chess.Board.from_epd(epd) == (board, {"id": "WAC.001", "bm": "Qg6"})
+        True
+        ```
         """
-    def generate_legal_captures(self) -> MoveGenerator:
+    @staticmethod
+    def validate_fen(fen:builtins.str) -> None:
         r"""
-        Generate the next remaining legal captures for the current board.
-        Exhausts the move generator if fully iterated over.
-        Updates the move generator.
+        Check if a FEN string is a valid position, raising a `ValueError` naming the problem if not.
+        Checks the format of each field, that each color has exactly one king, that no pawns are on the first or last rank,
+        that the castling rights match the kings and rooks, that the en passant square matches a pawn that just moved two squares,
+        and that the side not to move is not in check.
+        
+        ```python
+        >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+        >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKKBNR w KQkq - 0 1")
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid FEN: white must have exactly one king (found 2)
+        >>> rust_chess.Board.validate_fen("rnbqkbnP/pppppppp/8/8/8/8/PPPPPPP1/RNBQKBNR w KQq - 0 1")
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid FEN: pawn on h8 (pawns can't be on the first or last rank)
+        >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQxq - 0 1")
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid FEN: invalid castling rights 'KQxq'
+        >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1")
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid FEN: invalid side to move 'x' (must be 'w' or 'b')
+        ```
         """
-    def is_insufficient_material(self) -> builtins.bool:
+    @staticmethod
+    def from_fen(fen:builtins.str, chess960:builtins.bool=False) -> Board:
         r"""
-        Checks if the side to move has insufficient material to checkmate the opponent.
-        The cases where this is true are:
-            1. K vs K
-            2. K vs K + N
-            3. K vs K + B
-            4. K + B vs K + B with the bishops on the same color.
+        Create a new board from a FEN string.
+        Raises a `ValueError` naming the problem if the FEN string is invalid (see `validate_fen`).
+        The side to move is case-insensitive ("W" and "B" are accepted).
+        
+        If `chess960` is True, the board is in Chess960 mode, and the castling rights can be in
+        X-FEN (`KQkq` for the outermost rooks, otherwise the file of the rook) or Shredder-FEN (e.g. `HAha`).
+        The castling rights are kept track of and written as X-FEN, but castling moves are not generated,
+        since the chess crate only supports standard castling.
         
         ```python
-        >>> rust_chess.Board().is_insufficient_material()
-        False
-        >>> rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K
-        True
-        >>> rust_chess.Board("4k3/8/8/8/5N2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + N
-        True
-        >>> rust_chess.Board("4k3/8/8/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + B
-        True
-        >>> rust_chess.Board("4k3/8/8/5b2/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B different color
-        False
-        >>> rust_chess.Board("4k3/8/5b2/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B same color
+        >>> rust_chess.Board.from_fen("rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2")
+        rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2
+        >>> rust_chess.Board.from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR W KQkq - 0 1").turn
         True
+        >>> board = rust_chess.Board.from_fen("bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1", chess960=True)
+        >>> board
+        bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w KQkq - 0 1
+        >>> len(board.get_legal_moves())
+        20
         ```
         """
-    def is_fifty_moves(self) -> builtins.bool:
+    def set_fen(self, fen:builtins.str) -> None:
         r"""
-        Checks if the halfmoves since the last pawn move or capture is >= 100
-        and the game is ongoing (not checkmate or stalemate).
+        Set the board to the position of a FEN string, clearing the move stack.
+        In Chess960 mode, the FEN string is parsed as a Chess960 position.
+        Reuses the existing move generator instead of creating a new one.
+        Raises a `ValueError` if the FEN string is invalid, leaving the board unchanged.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.set_fen("4k3/8/8/8/8/8/8/4K2R w K - 3 40")
+        >>> board.get_fen()
+        '4k3/8/8/8/8/8/8/4K2R w K - 3 40'
+        >>> len(list(board.generate_legal_moves()))
+        15
+        ```
+        """
+    def reset(self) -> None:
+        r"""
+        Reset the board to the starting position, clearing the move stack.
+        Reuses the existing move generator instead of creating a new one.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.push(rust_chess.Move("e2e4"))
+        >>> board.push(rust_chess.Move("e7e5"))
+        >>> board.reset()
+        >>> board
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+        >>> len(list(board.generate_legal_moves()))
+        20
+        ```
+        """
+    def get_piece_type_on(self, square:Square) -> typing.Optional[PieceType]:
+        r"""
+        Get the piece type on a square, otherwise None.
+        Different than `get_piece_on` because it returns the piece type, which does not include color.
+        
+        ```python
+        >>> rust_chess.Board().get_piece_type_on(rust_chess.A1)
+        R
+        >>> rust_chess.Board().get_piece_type_on(rust_chess.E8)
+        K
+        ```
+        """
+    def get_color_on(self, square:Square) -> typing.Optional[Color]:
+        r"""
+        Get the color of the piece on a square, otherwise None.
+        
+        ```python
+        >>> rust_chess.Board().get_color_on(rust_chess.A1)
+        True
+        >>> print(rust_chess.Board().get_color_on(rust_chess.A1))
+        WHITE
+        >>> rust_chess.Board().get_color_on(rust_chess.E8)
+        False
+        """
+    def get_piece_on(self, square:Square) -> typing.Optional[Piece]:
+        r"""
+        Get the piece on a square (color-inclusive), otherwise None.
+        Different than `get_piece_on` because it returns the piece, which includes color.
+        
+        ```python
+        >>> rust_chess.Board().get_piece_on(rust_chess.A1)
+        R
+        >>> rust_chess.Board().get_piece_on(rust_chess.E8)
+        k
+        ```
+        """
+    def piece_map(self) -> builtins.dict[Square, Piece]:
+        r"""
+        Get a dictionary of every occupied square to the piece on it, ordered by square.
+        
+        ```python
+        >>> piece_map = rust_chess.Board().piece_map()
+        >>> len(piece_map)
+        32
+        >>> piece_map[rust_chess.A1]
+        R
+        >>> rust_chess.E4 in piece_map
+        False
+        ```
+        """
+    def enumerate_pieces(self) -> builtins.list[tuple[Square, PieceType, Color]]:
+        r"""
+        Get a list of every occupied square with its piece type and color, ordered by square.
+        
+        ```python
+        >>> pieces = rust_chess.Board().enumerate_pieces()
+        >>> len(pieces)
+        32
+        >>> pieces[0]
+        (a1, R, True)
+        >>> pieces[0] == (rust_chess.A1, rust_chess.ROOK, rust_chess.WHITE)
+        True
+        ```
+        """
+    def get_king_square(self, color:Color) -> Square:
+        r"""
+        Get the king square of a certain color
+        """
+    def is_zeroing(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if a move is a capture or a pawn move.
+        Doesn't check legality.
+        """
+    def captured_square(self, chess_move:Move) -> typing.Optional[Square]:
+        r"""
+        Get the square of the piece a move would capture, otherwise None.
+        For en passant, this is the square of the captured pawn (not the destination square).
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4")
+        >>> board.captured_square(rust_chess.Move("e5f6"))
+        f5
+        >>> board.captured_square(rust_chess.Move("e5e6"))
+        
+        ```
+        """
+    def captured_piece(self, chess_move:Move) -> typing.Optional[Piece]:
+        r"""
+        Get the piece a move would capture (color-inclusive), otherwise None.
+        Handles en passant, where the captured pawn is not on the destination square.
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4")
+        >>> board.captured_piece(rust_chess.Move("e5f6"))
+        p
+        >>> rust_chess.Board("r3k3/8/8/8/8/8/8/R3K3 w - - 0 1").captured_piece(rust_chess.Move("a1a8"))
+        r
+        ```
+        """
+    def is_capture(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if a move is a capture (including en passant).
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+        >>> board.is_capture(rust_chess.Move("e4d5"))
+        True
+        >>> board.is_capture(rust_chess.Move("e4e5"))
+        False
+        ```
+        """
+    def is_en_passant(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if a move is an en passant capture, using the en passant square of the board.
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4")
+        >>> board.is_en_passant(rust_chess.Move("e5f6"))
+        True
+        >>> board.is_en_passant(rust_chess.Move("e5d6"))
+        False
+        ```
+        """
+    def is_castling(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if a move is castling (the king moving two squares).
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1")
+        >>> board.is_castling(rust_chess.Move("e1g1"))
+        True
+        >>> board.is_castling(rust_chess.Move("e1c1"))
+        True
+        >>> board.is_castling(rust_chess.Move("e1f1"))
+        False
+        ```
+        """
+    def gives_check(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if a move gives check (including discovered checks).
+        Doesn't check legality, but moves without a piece of the side to move on the source square don't give check.
+        
+        ```python
+        >>> board = rust_chess.Board("4k3/8/8/8/8/8/3B4/3RK3 w - - 0 1")
+        >>> board.gives_check(rust_chess.Move("d1d8")) # Direct check
+        True
+        >>> board.gives_check(rust_chess.Move("e1f2")) # Quiet move
+        False
+        >>> board = rust_chess.Board("4k3/8/8/8/8/8/4B3/4R1K1 w - - 0 1")
+        >>> board.gives_check(rust_chess.Move("e2d3")) # Discovered check
+        True
+        >>> board.gives_check(rust_chess.Move("e4e5")) # No piece on the source square
+        False
+        ```
+        """
+    def gives_checkmate(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if a move gives checkmate.
+        Doesn't check legality, but moves without a piece of the side to move on the source square don't give checkmate.
+        
+        ```python
+        >>> rust_chess.Board("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").gives_checkmate(rust_chess.Move("a1a8")) # Back-rank mate
+        True
+        >>> rust_chess.Board("6k1/5pp1/8/8/8/8/8/R5K1 w - - 0 1").gives_checkmate(rust_chess.Move("a1a8")) # The king escapes to h7
+        False
+        >>> rust_chess.Board().gives_checkmate(rust_chess.Move("e4e5")) # No piece on the source square
+        False
+        ```
+        """
+    def san(self, chess_move:Move, check_suffix:builtins.bool=True) -> builtins.str:
+        r"""
+        Get the Standard Algebraic Notation (SAN) of a move (e.g. "Nf3", "exd5", "O-O", "e8=Q#").
+        If `check_suffix` is False, the "+" and "#" suffixes for check and checkmate are left out.
+        Raises a `ValueError` if there is no piece on the source square.
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("rnbqkbnr/pppp1ppp/8/4p3/5P2/8/PPPPP1PP/RNBQKBNR w KQkq - 0 2")
+        >>> board.san(rust_chess.Move("f4e5"))
+        'fxe5'
+        >>> board = rust_chess.Board("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+        >>> board.san(rust_chess.Move("d1h5"))
+        'Qh5'
+        >>> board = rust_chess.Board("r1bqkbnr/pppp1ppp/2n5/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 2 3")
+        >>> board.san(rust_chess.Move("h5f7"))
+        'Qxf7#'
+        >>> board.san(rust_chess.Move("h5f7"), check_suffix=False)
+        'Qxf7'
+        ```
+        """
+    def mvv_lva_score(self, chess_move:Move) -> builtins.int:
+        r"""
+        Get the MVV-LVA (most valuable victim, least valuable attacker) score of a move for ordering captures.
+        Captures score ten times the victim's value minus the attacker's index (0 for pawns to 5 for kings),
+        so any capture of a more valuable piece scores higher. Non-captures score 0.
+        Handles en passant. Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("4k3/8/8/3q4/4P3/8/3p4/3QK3 w - - 0 1")
+        >>> board.mvv_lva_score(rust_chess.Move("e4d5")) # PxQ
+        9000
+        >>> board.mvv_lva_score(rust_chess.Move("d1d2")) # QxP
+        996
+        >>> board.mvv_lva_score(rust_chess.Move("e4e5")) # Quiet move
+        0
+        ```
+        """
+    def see(self, chess_move:Move) -> builtins.int:
+        r"""
+        Get the static exchange evaluation (SEE) of a move: the material gained in centipawns if both sides
+        keep recapturing on the destination square with their least valuable piece, and can stop when it's better to.
+        Includes x-ray attackers behind other attackers, but ignores pins. Non-captures score 0.
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board("4k3/8/8/3p4/8/8/8/3RK3 w - - 0 1")
+        >>> board.see(rust_chess.Move("d1d5")) # Undefended pawn
+        100
+        >>> board = rust_chess.Board("4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1")
+        >>> board.see(rust_chess.Move("d1d5")) # Pawn defended by a pawn
+        -800
+        >>> board = rust_chess.Board("3rk3/8/8/3p4/8/8/3R4/3RK3 w - - 0 1")
+        >>> board.see(rust_chess.Move("d2d5")) # Rook exchange after winning a pawn
+        100
+        ```
+        """
+    def move_sort_key(self, chess_move:Move) -> builtins.int:
+        r"""
+        Get an integer sort key for ordering moves (higher is more promising).
+        Combines the capture value (MVV-LVA), a promotion bonus, and a check bonus.
+        Captures rank above non-capturing moves, except for captures that lose material (negative `see`),
+        which rank below them.
+        Doesn't check legality, but moves without a piece of the side to move on the source square score 0.
+        
+        ```python
+        >>> board = rust_chess.Board("4k3/8/8/3q4/4P3/8/8/4K2R w K - 0 1")
+        >>> board.move_sort_key(rust_chess.Move("e4d5")) # PxQ
+        9000
+        >>> board.move_sort_key(rust_chess.Move("h1h8")) # Quiet check
+        500
+        >>> board.move_sort_key(rust_chess.Move("h1h2")) # Quiet move
+        0
+        >>> board = rust_chess.Board("4k3/8/4p3/3p4/q7/1P6/8/3QK3 w - - 0 1")
+        >>> queen_capture, quiet, losing_capture = rust_chess.Move("b3a4"), rust_chess.Move("e1f2"), rust_chess.Move("d1d5")
+        >>> board.move_sort_key(queen_capture) > board.move_sort_key(quiet) > board.move_sort_key(losing_capture)
+        True
+        >>> moves = sorted(board.generate_legal_moves(), key=board.move_sort_key, reverse=True)
+        >>> moves[0], moves[-1]
+        (Move(b3, a4, None), Move(d1, d5, None))
+        >>> board.move_sort_key(rust_chess.Move("e4e5")) # No piece on the source square
+        0
+        ```
+        """
+    def is_legal_move(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if the move is legal (supposedly very slow according to the chess crate).
+        Use this function for moves not generated by the move generator.
+        `is_legal_quick` is faster for moves generated by the move generator.
+        
+        ```python
+        >>> move = rust_chess.Move("e2e4")
+        >>> rust_chess.Board().is_legal_move(move)
+        True
+        >>> move2 = rust_chess.Move("e2e5")
+        >>> rust_chess.Board().is_legal_move(move2)
+        False
+        ```
+        """
+    def mirror(self) -> Board:
+        r"""
+        Get a new board with the colors swapped and the board flipped vertically (e.g. a white pawn on e4 becomes a black pawn on e5).
+        The side to move, castling rights, and en passant square are swapped too, and the move counters are kept.
+        The new board has no move history.
+        
+        ```python
+        >>> board = rust_chess.Board("r3k2r/pp6/8/8/3pP3/8/8/R3K3 b Qkq e3 0 1")
+        >>> board.mirror()
+        r3k3/8/8/3Pp3/8/8/PP6/R3K2R w KQq e6 0 1
+        >>> board.mirror().mirror() == board
+        True
+        ```
+        """
+    def mirror_move(self, chess_move:Move) -> Move:
+        r"""
+        Get the move corresponding to a move on the color-mirrored board (ranks flipped and colors swapped).
+        The promotion piece is kept, and castling moves map to the mirrored castling moves.
+        
+        ```python
+        >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+        >>> move = board.mirror_move(rust_chess.Move("e7e5"))
+        >>> move
+        Move(e2, e4, None)
+        >>> rust_chess.Board("rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").is_legal_move(move)
+        True
+        ```
+        """
+    def is_legal(self, move_or_uci:typing.Any) -> builtins.bool:
+        r"""
+        Check if a move is legal, given either a move or a UCI string (e.g. "e2e4").
+        Raises a `ValueError` if the argument is neither a move nor a valid UCI string.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.is_legal(rust_chess.Move("e2e4"))
+        True
+        >>> board.is_legal("e2e4")
+        True
+        >>> board.is_legal("e2e5")
+        False
+        ```
+        """
+    def is_legal_quick(self, chess_move:Move) -> builtins.bool:
+        r"""
+        Check if a move generated by the move generator is legal (much faster than `is_legal_move`).
+        Only use this function for moves generated by the move generator for the current board.
+        Passing any other move is undefined behavior (it may return a wrong answer or crash).
+        Use `is_legal_move` for moves not generated by the move generator.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> all(board.is_legal_quick(move) for move in board.generate_legal_moves())
+        True
+        ```
+        """
+    def null_move_ok(self) -> builtins.bool:
+        r"""
+        Checks if a null move is reasonably safe for null-move pruning.
+        Returns False if the side to move is in check or only has a king and pawns (zugzwang-prone).
+        
+        ```python
+        >>> rust_chess.Board().null_move_ok()
+        True
+        >>> rust_chess.Board("4k3/4p3/8/8/8/8/4P3/4K3 w - - 0 1").null_move_ok()
+        False
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").null_move_ok()
+        False
+        ```
+        """
+    def make_null_move_new(self) -> typing.Optional[Board]:
+        r"""
+        Make a null move onto a new board.
+        Returns None if the current player is in check.
+        
+        ```python
+        >>> rust_chess.Board().make_null_move_new()
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 1 1
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").make_null_move_new()
+        
+        ```
+        """
+    def make_null_move(self) -> builtins.bool:
+        r"""
+        Make a null move on the current board.
+        Returns whether the null move was made (False if the current player is in check).
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.make_null_move()
+        True
+        >>> board.turn
+        False
+        ```
+        """
+    def make_move_new(self, chess_move:Move, check_legality:builtins.bool=False) -> Board:
+        r"""
+        Make a move onto a new board.
+        The new board has its own move generator, so the current board's move generator is not updated.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> moves = board.generate_legal_moves()
+        >>> new_board = board.make_move_new(rust_chess.Move("e2e4"))
+        >>> rust_chess.Move("e2e4") in list(moves)
+        True
+        ```
+        """
+    def play(self, chess_move:Move) -> tuple[Board, builtins.str]:
+        r"""
+        Make a move onto a new board and get the SAN of the move in the current position.
+        Raises a `ValueError` if the move is illegal.
+        
+        ```python
+        >>> board, san = rust_chess.Board().play(rust_chess.Move("g1f3"))
+        >>> san
+        'Nf3'
+        >>> board
+        rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKB1R b KQkq - 1 1
+        ```
+        """
+    def make_move(self, chess_move:Move, check_legality:builtins.bool=False) -> None:
+        r"""
+        Make a move on the current board.
+        The board gets a new move generator for the new position.
+        A generator from before the move is not invalidated and keeps yielding the old position's moves.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> moves = board.generate_legal_moves()
+        >>> board.make_move(rust_chess.Move("e2e4"))
+        >>> len(list(moves))
+        20
+        >>> len(list(board.generate_legal_moves()))
+        20
+        >>> rust_chess.Move("e7e5") in board.get_legal_moves()
+        True
+        ```
+        """
+    def push(self, chess_move:Move, check_legality:builtins.bool=False) -> None:
+        r"""
+        Make a move on the current board and add it to the move stack.
+        Same as `make_move`.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.push(rust_chess.Move("e2e4"))
+        >>> board
+        rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1
+        ```
+        """
+    def push_uci(self, uci:builtins.str) -> Move:
+        r"""
+        Make a move from a UCI string (e.g. "e2e4") on the current board and return the move.
+        Raises a `ValueError` if the UCI string is invalid or the move is illegal.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.push_uci("e2e4")
+        Move(e2, e4, None)
+        >>> board.push_uci("e2e4")
+        Traceback (most recent call last):
+        ...
+        ValueError: Illegal move
+        ```
+        """
+    def push_san(self, san:builtins.str) -> Move:
+        r"""
+        Make a move from a SAN string (e.g. "Nf3") on the current board and return the move.
+        Raises a `ValueError` if the SAN string doesn't match exactly one legal move.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> for san in ["e4", "e5", "Nf3"]:
+        ...     _ = board.push_san(san)
+        >>> board
+        rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2
+        ```
+        """
+    def pop(self) -> Move:
+        r"""
+        Undo the last move on the move stack and return it.
+        Restores the previous position, move counters, and move generator.
+        A null move is returned as `Move(a1, a1, None)`.
+        Raises a `ValueError` if there are no moves to undo.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.push(rust_chess.Move("e2e4"))
+        >>> board.pop()
+        Move(e2, e4, None)
+        >>> board
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+        ```
+        """
+    def peek(self) -> typing.Optional[Move]:
+        r"""
+        Get the last move on the move stack without undoing it, otherwise None.
+        A null move is returned as `Move(a1, a1, None)`.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.peek()
+        
+        >>> board.push(rust_chess.Move("e2e4"))
+        >>> board.peek()
+        Move(e2, e4, None)
+        ```
+        """
+    def get_pinned_bitboard(self) -> Bitboard:
+        r"""
+        Get the bitboard of the side to move's pinned pieces
+        """
+    def pinned(self) -> Bitboard:
+        r"""
+        Get the bitboard of the side to move's pieces that are pinned to their king.
+        Same as `get_pinned_bitboard`.
+        
+        ```python
+        >>> list(rust_chess.Board("4r1k1/8/8/8/8/8/4N3/4K3 w - - 0 1").pinned())
+        [e2]
+        ```
+        """
+    def is_pinned(self, square:Square) -> builtins.bool:
+        r"""
+        Checks if the piece on a square is one of the side to move's pieces pinned to their king.
+        The opponent's pieces are never reported as pinned.
+        
+        ```python
+        >>> board = rust_chess.Board("4r1k1/8/8/8/8/8/4N3/4K3 w - - 0 1")
+        >>> board.is_pinned(rust_chess.E2)
+        True
+        >>> board.is_pinned(rust_chess.E1)
+        False
+        ```
+        """
+    def get_checkers_bitboard(self) -> Bitboard:
+        r"""
+        Get the bitboard of the pieces putting the side to move in check
+        """
+    def checkers(self) -> Bitboard:
+        r"""
+        Get the bitboard of the pieces putting the side to move in check.
+        Same as `get_checkers_bitboard`.
+        
+        ```python
+        >>> list(rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").checkers())
+        [h4]
+        ```
+        """
+    def is_double_check(self) -> builtins.bool:
+        r"""
+        Checks if the side to move is in check from two pieces at once.
+        Only king moves can escape a double check.
+        
+        ```python
+        >>> rust_chess.Board("4k3/8/5N2/8/8/8/8/4R1K1 b - - 0 1").is_double_check()
+        True
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_double_check()
+        False
+        ```
+        """
+    def checking_squares(self) -> builtins.list[Square]:
+        r"""
+        Get a list of the squares of the pieces putting the side to move in check, ordered by square.
+        
+        ```python
+        >>> rust_chess.Board("4k3/8/5N2/8/8/8/8/4R1K1 b - - 0 1").checking_squares()
+        [e1, f6]
+        >>> rust_chess.Board().checking_squares()
+        []
+        ```
+        """
+    def get_all_bitboard(self) -> Bitboard:
+        r"""
+        Get the bitboard of all the pieces
+        """
+    def get_color_bitboard(self, color:Color) -> Bitboard:
+        r"""
+        Get the bitboard of all the pieces of a certain color
+        """
+    def get_piece_type_bitboard(self, piece_type:PieceType) -> Bitboard:
+        r"""
+        Get the bitboard of all the pieces of a certain type
+        """
+    def get_piece_bitboard(self, piece:Piece) -> Bitboard:
+        r"""
+        Get the bitboard of all the pieces of a certain color and type
+        """
+    def combined(self) -> Bitboard:
+        r"""
+        Get the bitboard of all the pieces.
+        Same as `get_all_bitboard`.
+        
+        ```python
+        >>> rust_chess.Board().combined().popcnt()
+        32
+        ```
+        """
+    def color_combined(self, color:Color) -> Bitboard:
+        r"""
+        Get the bitboard of all the pieces of a certain color.
+        Same as `get_color_bitboard`.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.color_combined(rust_chess.WHITE) & board.color_combined(rust_chess.BLACK) == rust_chess.Bitboard(0)
+        True
+        ```
+        """
+    def pieces(self, piece_type:PieceType) -> Bitboard:
+        r"""
+        Get the bitboard of all the pieces of a certain type (both colors).
+        Same as `get_piece_type_bitboard`.
+        
+        ```python
+        >>> rust_chess.Board().pieces(rust_chess.PAWN).popcnt()
+        16
+        ```
+        """
+    def pieces_colored(self, piece_type:PieceType, color:Color) -> Bitboard:
+        r"""
+        Get the bitboard of the pieces of a certain type and color.
+        
+        ```python
+        >>> list(rust_chess.Board().pieces_colored(rust_chess.ROOK, rust_chess.WHITE))
+        [a1, h1]
+        ```
+        """
+    def pawn_attackers(self, color:Color, square:Square) -> Bitboard:
+        r"""
+        Get the bitboard of the pawns of a certain color attacking a square.
+        
+        ```python
+        >>> board = rust_chess.Board("4k3/8/8/2p1p3/8/8/8/4K3 w - - 0 1")
+        >>> list(board.pawn_attackers(rust_chess.BLACK, rust_chess.D4))
+        [c5, e5]
+        ```
+        """
+    def is_pawn_attacked(self, color:Color, square:Square) -> builtins.bool:
+        r"""
+        Check if a square is attacked by any pawn of a certain color.
+        
+        ```python
+        >>> board = rust_chess.Board("4k3/8/8/2p1p3/8/8/8/4K3 w - - 0 1")
+        >>> board.is_pawn_attacked(rust_chess.BLACK, rust_chess.D4)
+        True
+        >>> board.is_pawn_attacked(rust_chess.BLACK, rust_chess.D5)
+        False
+        ```
+        """
+    def attackers(self, color:Color, square:Square) -> Bitboard:
+        r"""
+        Get the bitboard of the pieces of a certain color attacking a square.
+        Sliding pieces are blocked by pieces in the way.
+        
+        ```python
+        >>> list(rust_chess.Board().attackers(rust_chess.WHITE, rust_chess.F3))
+        [g1, e2, g2]
+        ```
+        """
+    def is_attacked(self, square:Square, by_color:Color) -> builtins.bool:
+        r"""
+        Check if a square is attacked by any piece of a certain color.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.is_attacked(rust_chess.F3, rust_chess.WHITE)
+        True
+        >>> board.is_attacked(rust_chess.E4, rust_chess.WHITE)
+        False
+        ```
+        """
+    def outposts(self, color:Color) -> Bitboard:
+        r"""
+        Get the bitboard of the outpost squares of a certain color.
+        An outpost is a square in the opponent's half of the board that is defended by a friendly pawn
+        and can never be attacked by an enemy pawn (no enemy pawns on the adjacent files ahead of it).
+        Squares are included whether or not they are occupied.
+        
+        ```python
+        >>> board = rust_chess.Board("r1bqkb1r/pp3ppp/2np1n2/4p3/4P3/2N5/PPP1BPPP/R1BQK1NR w KQkq - 0 7")
+        >>> list(board.outposts(rust_chess.WHITE))
+        [d5]
+        ```
+        """
+    def remove_move(self, chess_move:Move) -> None:
+        r"""
+        Remove a move from the move generator.
+        Prevents the move from being generated.
+        Useful if you already have a certain move and don't need to generate it again.
+        """
+    def remove_move_mask(self, mask:Bitboard) -> None:
+        r"""
+        Remove the moves onto squares in a mask from the move generator.
+        Prevents the moves from being generated until the move generator is reset.
+        Useful for skipping a group of moves (e.g. all captures by removing the enemy pieces).
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.remove_move_mask(rust_chess.Bitboard(rust_chess.E4))
+        >>> moves = list(board.generate_legal_moves())
+        >>> len(moves)
+        19
+        >>> any(move.dest == rust_chess.E4 for move in moves)
+        False
+        ```
+        """
+    def reset_move_generator(self) -> None:
+        r"""
+        Reset the move generator for the current board
+        """
+    def next_move(self) -> typing.Optional[Move]:
+        r"""
+        Get the next remaining move of the generator.
+        Updates the move generator to the next move.
+        Unless the mask is set, this will return the next legal move by default.
+        """
+    def take_moves(self, n:builtins.int) -> builtins.list[Move]:
+        r"""
+        Get up to `n` of the next remaining moves of the generator as a list.
+        Updates the move generator, so repeated calls stream through the remaining moves.
+        Returns an empty list once the move generator is exhausted.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> len(board.take_moves(15))
+        15
+        >>> len(board.take_moves(15))
+        5
+        >>> board.take_moves(15)
+        []
+        ```
+        """
+    def get_legal_moves(self) -> builtins.list[Move]:
+        r"""
+        Get a list of all legal moves for the current board.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> len(board.get_legal_moves())
+        20
+        >>> board.get_legal_moves() == board.get_legal_moves()
+        True
+        ```
+        """
+    def perft(self, depth:builtins.int) -> builtins.int:
+        r"""
+        Count the leaf nodes of the legal move tree to a depth (perft).
+        Useful for validating and benchmarking move generation against known values.
+        
+        ```python
+        >>> rust_chess.Board().perft(3)
+        8902
+        >>> rust_chess.Board("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").perft(2)
+        2039
+        ```
+        """
+    def perft_parallel(self, depth:builtins.int) -> builtins.int:
+        r"""
+        Count the leaf nodes of the legal move tree to a depth (perft), using multiple threads.
+        The legal moves are split across threads, and the GIL is released while counting.
+        Gives the same result as `perft`.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.perft_parallel(4) == board.perft(4)
+        True
+        ```
+        """
+    def perft_divide(self, depth:builtins.int) -> tuple[builtins.dict[builtins.str, builtins.int], builtins.int]:
+        r"""
+        Count the leaf nodes under each legal move to a depth (perft divide).
+        Returns a map from the UCI string of each legal move to its node count at one less depth (ordered by UCI string),
+        and the total node count (the same as `perft`).
+        At depth 0 there are no moves to divide, so the map is empty and the total is 1.
+        Useful for finding move generation differences against a reference engine.
+        
+        ```python
+        >>> divide, total = rust_chess.Board().perft_divide(2)
+        >>> len(divide), set(divide.values())
+        (20, {20})
+        >>> divide["e2e4"]
+        20
+        >>> total
+        400
+        >>> rust_chess.Board().perft_divide(0)
+        ({}, 1)
+        ```
+        """
+    def fill_legal_moves(self, out:list) -> None:
+        r"""
+        Clear a list and fill it with all legal moves for the current board.
+        Reusing the same list avoids creating a new list for every position.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> moves = []
+        >>> board = rust_chess.Board()
+        >>> board.fill_legal_moves(moves)
+        >>> moves == board.get_legal_moves()
+        True
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").fill_legal_moves(moves)
+        >>> moves
+        []
+        ```
+        """
+    def count_legal_moves(self) -> builtins.int:
+        r"""
+        Count the legal moves for the current board without creating a list of moves.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> rust_chess.Board().count_legal_moves()
+        20
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").count_legal_moves()
+        0
+        ```
+        """
+    def legal_destination_map(self) -> builtins.dict[Square, Bitboard]:
+        r"""
+        Get a dictionary of each square with a piece of the side to move to a bitboard of its legal destinations.
+        Pinned pieces and check are accounted for, so pieces that can't move have an empty bitboard.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> destinations = rust_chess.Board("4k3/4r3/8/8/8/8/4R3/1N2K3 w - - 0 1").legal_destination_map()
+        >>> list(destinations[rust_chess.E2])
+        [e3, e4, e5, e6, e7]
+        >>> list(destinations[rust_chess.B1])
+        [d2, a3, c3]
+        ```
+        """
+    def random_move(self, seed:typing.Optional[builtins.int]=None) -> typing.Optional[Move]:
+        r"""
+        Get a random legal move, otherwise None if there are no legal moves.
+        Each legal move is equally likely, and the same seed always gives the same move.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> board.random_move(seed=42) == board.random_move(seed=42)
+        True
+        >>> board.random_move() in board.get_legal_moves()
+        True
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").random_move()
+        
+        ```
+        """
+    def play_random_game(self, max_plies:builtins.int, seed:typing.Optional[builtins.int]=None) -> builtins.list[Move]:
+        r"""
+        Play random legal moves on the board until the game is over or a number of plies is reached.
+        Returns the list of moves played, which stay on the move stack.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> moves = board.play_random_game(20, seed=42)
+        >>> len(moves)
+        20
+        >>> replay = rust_chess.Board()
+        >>> for move in moves:
+        ...     replay.push(move)
+        >>> replay.get_fen() == board.get_fen()
+        True
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").play_random_game(10)
+        []
+        ```
+        """
+    def moves_from(self, square:Square) -> builtins.list[Move]:
+        r"""
+        Get a list of the legal moves originating from a square.
+        Returns an empty list immediately if the square is empty or holds a piece of the side not to move.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> rust_chess.Board().moves_from(rust_chess.G1)
+        [Move(g1, f3, None), Move(g1, h3, None)]
+        >>> rust_chess.Board().moves_from(rust_chess.E4)
+        []
+        ```
+        """
+    def legal_moves_from(self, square:Square) -> builtins.list[Move]:
+        r"""
+        Get a list of the legal moves originating from a square.
+        Same as `moves_from`.
+        
+        ```python
+        >>> rust_chess.Board().legal_moves_from(rust_chess.G1)
+        [Move(g1, f3, None), Move(g1, h3, None)]
+        >>> rust_chess.Board().legal_moves_from(rust_chess.E4)
+        []
+        ```
+        """
+    def legal_moves_for(self, piece_type:PieceType) -> builtins.list[Move]:
+        r"""
+        Get a list of the legal moves made by pieces of a type for the side to move.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> len(board.legal_moves_for(rust_chess.KNIGHT))
+        4
+        >>> len(board.legal_moves_for(rust_chess.PAWN))
+        16
+        >>> board.legal_moves_for(rust_chess.QUEEN)
+        []
+        ```
+        """
+    def legal_promotions(self) -> builtins.list[Move]:
+        r"""
+        Get a list of the legal promotion moves (including capture promotions).
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> board = rust_chess.Board("1n5k/P7/8/8/8/8/8/K7 w - - 0 1")
+        >>> moves = board.legal_promotions()
+        >>> moves[:4]
+        [Move(a7, a8, QUEEN), Move(a7, a8, KNIGHT), Move(a7, a8, ROOK), Move(a7, a8, BISHOP)]
+        >>> moves[4:]
+        [Move(a7, b8, QUEEN), Move(a7, b8, KNIGHT), Move(a7, b8, ROOK), Move(a7, b8, BISHOP)]
+        >>> rust_chess.Board().legal_promotions()
+        []
+        ```
+        """
+    def legal_evasions(self) -> builtins.list[Move]:
+        r"""
+        Get a list of the legal moves that get out of check (king moves, blocks, and captures of the checker).
+        Returns an empty list if the side to move is not in check.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> board = rust_chess.Board("4k3/8/8/3N4/1b6/8/8/4K3 w - - 0 1")
+        >>> board.legal_evasions()
+        [Move(d5, c3, None), Move(d5, b4, None), Move(e1, d1, None), Move(e1, f1, None), Move(e1, e2, None), Move(e1, f2, None)]
+        >>> rust_chess.Board().legal_evasions()
+        []
+        ```
+        """
+    def generate_legal_moves(self) -> MoveGenerator:
+        r"""
+        Generate the next remaining legal moves for the current board.
+        If the move generator is exhausted, it is reset first, so each call yields all legal moves
+        unless a previous iteration was stopped partway through.
+        Updates the move generator.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> len(list(board.generate_legal_moves()))
+        20
+        >>> len(list(board.generate_legal_moves()))
+        20
+        ```
+        """
+    def generate_legal_captures(self) -> MoveGenerator:
+        r"""
+        Generate the next remaining legal captures for the current board.
+        Exhausts the move generator if fully iterated over.
+        Updates the move generator.
+        """
+    def generate_quiet_moves(self) -> MoveGenerator:
+        r"""
+        Generate the next remaining legal quiet moves (non-captures) for the current board.
+        Includes castling and promotions onto empty squares.
+        En passant captures land on an empty square, so they are also included.
+        Exhausts the move generator if fully iterated over.
+        Updates the move generator.
+        
+        ```python
+        >>> len(list(rust_chess.Board().generate_quiet_moves()))
+        20
+        ```
+        """
+    def is_insufficient_material(self) -> builtins.bool:
+        r"""
+        Checks if the side to move has insufficient material to checkmate the opponent.
+        The cases where this is true are:
+            1. K vs K
+            2. K vs K + N
+            3. K vs K + B
+            4. K + B vs K + B with the bishops on the same color.
+        
+        ```python
+        >>> rust_chess.Board().is_insufficient_material()
+        False
+        >>> rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K
+        True
+        >>> rust_chess.Board("4k3/8/8/8/5N2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + N
+        True
+        >>> rust_chess.Board("4k3/8/8/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + B
+        True
+        >>> rust_chess.Board("4k3/8/8/5b2/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B different color
+        False
+        >>> rust_chess.Board("4k3/8/5b2/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B same color
+        True
+        ```
+        """
+    def is_fifty_moves(self) -> builtins.bool:
+        r"""
+        Checks if the halfmoves since the last pawn move or capture is >= 100
+        and the game is ongoing (not checkmate or stalemate).
+        
+        ```python
+        >>> rust_chess.Board().is_fifty_moves
+        False
+        >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 50 1").is_fifty_moves()
+        True
+        ```
+        """
+    def is_seventy_five_moves(self) -> builtins.bool:
+        r"""
+        Checks if the halfmoves since the last pawn move or capture is >= 150
+        and the game is ongoing (not checkmate or stalemate).
+        """
+    def can_claim_fifty_moves(self) -> builtins.bool:
+        r"""
+        Checks if the side to move can claim a draw by the fifty-move rule
+        (halfmoves since the last pawn move or capture is >= 100).
+        Unlike the seventy-five-move rule in `get_status`, this draw is not automatic and must be claimed.
+        
+        ```python
+        >>> rust_chess.Board().can_claim_fifty_moves()
+        False
+        >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").can_claim_fifty_moves()
+        True
+        ```
+        """
+    def can_claim_threefold_repetition(self) -> builtins.bool:
+        r"""
+        Checks if the side to move can claim a draw by threefold repetition
+        (the current position has occurred at least three times).
+        Unlike fivefold repetition in `get_status`, this draw is not automatic and must be claimed.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> for _ in range(2):
+        ...     for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+        ...         board.make_move(rust_chess.Move(uci))
+        >>> board.can_claim_threefold_repetition()
+        True
+        ```
+        """
+    def can_claim_draw(self) -> builtins.bool:
+        r"""
+        Checks if the side to move can claim a draw by the fifty-move rule or threefold repetition.
+        
+        ```python
+        >>> rust_chess.Board().can_claim_draw()
+        False
+        >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").can_claim_draw()
+        True
+        ```
+        """
+    def is_fivefold_repetition(self) -> builtins.bool:
+        r"""
+        Checks if the current position has occurred at least five times.
+        Only positions since the last pawn move or capture are considered,
+        since positions before an irreversible move cannot repeat.
+        Positions before the board was created (e.g. from a FEN string) are unknown.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> for _ in range(4):
+        ...     for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+        ...         board.make_move(rust_chess.Move(uci))
+        >>> board.is_fivefold_repetition()
+        True
+        ```
+        """
+    def is_repetition(self, count:builtins.int) -> builtins.bool:
+        r"""
+        Checks if the current position has occurred at least a number of times (including the current position).
+        Only positions since the last pawn move or capture are considered.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+        ...     board.make_move(rust_chess.Move(uci))
+        >>> board.is_repetition(2), board.is_repetition(3)
+        (True, False)
+        >>> for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+        ...     board.make_move(rust_chess.Move(uci))
+        >>> board.is_repetition(3)
+        True
+        ```
+        """
+    def is_check(self) -> builtins.bool:
+        r"""
+        Checks if the side to move is in check.
+        
+        ```python
+        >>> rust_chess.Board().is_check
+        False
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_check()
+        True
+        ```
+        """
+    def is_stalemate(self) -> builtins.bool:
+        r"""
+        Checks if the side to move is in stalemate
+        """
+    def is_checkmate(self) -> builtins.bool:
+        r"""
+        Checks if the side to move is in checkmate
+        """
+    def is_quiet(self) -> builtins.bool:
+        r"""
+        Checks if the position is quiet: the side to move is not in check
+        and has no legal captures (including en passant) or promotions.
+        Quiet positions are stable enough to evaluate statically.
+        
+        ```python
+        >>> rust_chess.Board().is_quiet()
+        True
+        >>> rust_chess.Board("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2").is_quiet()
+        False
+        ```
+        """
+    def checkmate_moves(self) -> builtins.list[Move]:
+        r"""
+        Get a list of the legal moves that immediately checkmate the opponent.
+        Uses a separate move generator, so the board's move generator is not updated.
+        
+        ```python
+        >>> rust_chess.Board("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").checkmate_moves()
+        [Move(a1, a8, None)]
+        >>> rust_chess.Board().checkmate_moves()
+        []
+        ```
+        """
+    def get_status(self) -> BoardStatus:
+        r"""
+        Get the status of the board.
+        The status is cached until the board changes, so repeated calls are cheap.
+        
+        ```python
+        >>> board = rust_chess.Board("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1")
+        >>> all(board.get_status() == rust_chess.BoardStatus.ONGOING for _ in range(1000))
+        True
+        >>> board.push(rust_chess.Move("a1a8"))
+        >>> board.get_status() == rust_chess.BoardStatus.CHECKMATE
+        True
+        >>> _ = board.pop()
+        >>> board.get_status() == rust_chess.BoardStatus.ONGOING
+        True
+        ```
+        """
+    def outcome(self, claim_draw:builtins.bool=False) -> typing.Optional[Outcome]:
+        r"""
+        Get the outcome of the game, otherwise None if the game is ongoing.
+        If `claim_draw` is True, a claimable fifty-move or threefold repetition draw also ends the game.
+        
+        ```python
+        >>> rust_chess.Board().outcome()
+        
+        >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").outcome()
+        Outcome(STALEMATE, None)
+        >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").outcome(claim_draw=True)
+        Outcome(FIFTY_MOVES, None)
+        ```
+        """
+    def result(self, claim_draw:builtins.bool=False) -> typing.Optional[builtins.str]:
+        r"""
+        Get the result of the game: "1-0", "0-1", "1/2-1/2", or None if the game is ongoing.
+        If `claim_draw` is True, a claimable fifty-move or threefold repetition draw also counts as a draw.
+        
+        ```python
+        >>> rust_chess.Board().result()
+        
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").result()
+        '0-1'
+        >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").result()
+        '1/2-1/2'
+        >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").result(claim_draw=True)
+        '1/2-1/2'
+        ```
+        """
+    def is_game_over(self, claim_draw:builtins.bool=False) -> builtins.bool:
+        r"""
+        Checks if the game is over (checkmate, stalemate, insufficient material, seventy-five-move rule, or fivefold repetition).
+        If `claim_draw` is True, a claimable fifty-move or threefold repetition draw also ends the game.
+        
+        ```python
+        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_game_over()
+        True
+        >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").is_game_over()
+        True
+        >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60")
+        >>> board.is_game_over(), board.is_game_over(claim_draw=True)
+        (False, True)
+        >>> rust_chess.Board().is_game_over(claim_draw=True)
+        False
+        ```
+        """
+
+class BoardBuilder:
+    r"""
+    Board builder class.
+    Builds a board piece by piece instead of from a FEN string.
+    Starts empty with white to move, no castling rights, and no en passant square.
+    The setters return the builder, so they can be chained.
+    
+    ```python
+    >>> builder = rust_chess.BoardBuilder()
+    >>> builder.set_piece(rust_chess.E1, rust_chess.Piece(rust_chess.KING, rust_chess.WHITE))
+    BoardBuilder(8/8/8/8/8/8/8/4K3 w - - 0 1)
+    >>> builder.set_piece(rust_chess.D1, rust_chess.Piece(rust_chess.QUEEN, rust_chess.WHITE)).set_piece(
+    ...     rust_chess.E8, rust_chess.Piece(rust_chess.KING, rust_chess.BLACK)
+    ... ).build()
+    4k3/8/8/8/8/8/8/3QK3 w - - 0 1
+    ```
+    """
+    def __new__(cls) -> BoardBuilder:
+        r"""
+        Create a new empty board builder.
         
         ```python
-        >>> rust_chess.Board().is_fifty_moves
-        False
-        >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 50 1").is_fifty_moves()
-        True
+        >>> rust_chess.BoardBuilder()
+        BoardBuilder(8/8/8/8/8/8/8/8 w - - 0 1)
         ```
         """
-    def is_seventy_five_moves(self) -> builtins.bool:
+    def set_piece(self, square:Square, piece:Piece) -> BoardBuilder:
         r"""
-        Checks if the halfmoves since the last pawn move or capture is >= 150
-        and the game is ongoing (not checkmate or stalemate).
+        Put a piece on a square, replacing any piece already on it.
+        Does not update the castling rights.
         """
-    def is_fivefold_repetition(self) -> builtins.bool:
+    def clear_square(self, square:Square) -> BoardBuilder:
         r"""
-        Checks if the game is in a fivefold repetition.
-        TODO: Currently not implementable due to no storage of past moves
+        Remove any piece from a square.
+        Does not update the castling rights.
+        
+        ```python
+        >>> rust_chess.BoardBuilder().set_piece(rust_chess.A1, rust_chess.Piece(rust_chess.ROOK, rust_chess.WHITE)).clear_square(rust_chess.A1)
+        BoardBuilder(8/8/8/8/8/8/8/8 w - - 0 1)
+        ```
         """
-    def is_check(self) -> builtins.bool:
+    def set_turn(self, color:Color) -> BoardBuilder:
         r"""
-        Checks if the side to move is in check.
+        Set the side to move.
         
         ```python
-        >>> rust_chess.Board().is_check
-        False
-        >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_check()
-        True
+        >>> rust_chess.BoardBuilder().set_turn(rust_chess.BLACK)
+        BoardBuilder(8/8/8/8/8/8/8/8 b - - 0 1)
         ```
         """
-    def is_stalemate(self) -> builtins.bool:
+    def set_castling_rights(self, color:Color, kingside:builtins.bool, queenside:builtins.bool) -> BoardBuilder:
         r"""
-        Checks if the side to move is in stalemate
+        Set the castling rights of a color.
+        The king and rooks must be on their starting squares for the board to build.
+        
+        ```python
+        >>> rust_chess.BoardBuilder().set_castling_rights(rust_chess.WHITE, True, False)
+        BoardBuilder(8/8/8/8/8/8/8/8 w K - 0 1)
+        ```
         """
-    def is_checkmate(self) -> builtins.bool:
+    def set_en_passant(self, square:typing.Optional[Square]) -> BoardBuilder:
         r"""
-        Checks if the side to move is in checkmate
+        Set the en passant square, or clear it with None.
+        Only the file of the square is used, so either the target square (e.g. e3)
+        or the square of the pawn that can be captured (e.g. e4) can be given.
+        
+        ```python
+        >>> rust_chess.BoardBuilder().set_turn(rust_chess.BLACK).set_en_passant(rust_chess.E4)
+        BoardBuilder(8/8/8/8/8/8/8/8 b - e3 0 1)
+        ```
         """
-    def get_status(self) -> BoardStatus:
+    def build(self) -> Board:
+        r"""
+        Build the board, with a halfmove clock of 0 and a fullmove number of 1.
+        Raises a `ValueError` naming the problem if the position is impossible
+        (e.g. not exactly one king per color, pawns on the first or last rank, or the side not to move in check).
+        
+        ```python
+        >>> builder = rust_chess.BoardBuilder().set_piece(rust_chess.E1, rust_chess.Piece(rust_chess.KING, rust_chess.WHITE))
+        >>> builder.build()
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid board: black must have exactly one king (found 0)
+        >>> builder.set_piece(rust_chess.E8, rust_chess.Piece(rust_chess.KING, rust_chess.BLACK)).set_piece(
+        ...     rust_chess.A8, rust_chess.Piece(rust_chess.KING, rust_chess.BLACK)
+        ... ).build()
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid board: black must have exactly one king (found 2)
+        >>> builder.set_piece(rust_chess.A8, rust_chess.Piece(rust_chess.ROOK, rust_chess.WHITE)).build()
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid board: the side not to move is in check
+        >>> builder.clear_square(rust_chess.A8).set_castling_rights(rust_chess.WHITE, True, False).build()
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid board: white castling rights need the king and rooks on their starting squares
+        >>> builder.set_castling_rights(rust_chess.WHITE, False, False).set_piece(
+        ...     rust_chess.D5, rust_chess.Piece(rust_chess.PAWN, rust_chess.WHITE)
+        ... ).set_en_passant(rust_chess.E6).build()
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid board: no pawn to capture en passant on e5
+        ```
+        """
+    def __repr__(self) -> builtins.str:
         r"""
-        Get the status of the board
+        Get the representation of the builder as a FEN string (e.g. "BoardBuilder(8/8/8/8/8/8/8/4K3 w - - 0 1)").
+        The en passant square is written as the target square, like in `Board.get_fen`.
         """
 
 class Color:
@@ -573,6 +2239,18 @@ class Color:
         False
         ```
         """
+    def __int__(self) -> builtins.int:
+        r"""
+        Get the index of the color as an integer (0 for white, 1 for black).
+        Useful for indexing tables by color.
+        
+        ```python
+        >>> int(rust_chess.WHITE)
+        0
+        >>> int(rust_chess.BLACK)
+        1
+        ```
+        """
     def __repr__(self) -> builtins.str:
         r"""
         Get the color as a boolean string.
@@ -584,6 +2262,18 @@ class Color:
         False
         ```
         """
+    def opposite(self) -> Color:
+        r"""
+        Get the opposite color.
+        Unlike `not color`, this returns a color instead of a boolean.
+        
+        ```python
+        >>> rust_chess.WHITE.opposite() == rust_chess.BLACK
+        True
+        >>> print(rust_chess.BLACK.opposite())
+        WHITE
+        ```
+        """
     def __eq__(self, other:typing.Any) -> builtins.bool:
         r"""
         Compare the color to another color or boolean.
@@ -670,6 +2360,22 @@ class Move:
         ```
         """
     @staticmethod
+    def from_san(san:builtins.str, board:Board) -> Move:
+        r"""
+        Create a new move from a Standard Algebraic Notation (SAN) string (e.g. "Nf3") on a board.
+        Check, checkmate, and annotation suffixes (e.g. "+", "#", "!?") are ignored, and "0-0" is accepted for castling.
+        Raises a `ValueError` if the SAN string doesn't match exactly one legal move.
+        
+        ```python
+        >>> rust_chess.Move.from_san("Nf3", rust_chess.Board())
+        Move(g1, f3, None)
+        >>> rust_chess.Move.from_san("e5", rust_chess.Board())
+        Traceback (most recent call last):
+        ...
+        ValueError: Invalid or illegal SAN move: e5
+        ```
+        """
+    @staticmethod
     def from_uci(uci:builtins.str) -> Move:
         r"""
         Create a new move from a UCI string (e.g. "e2e4").
@@ -706,7 +2412,88 @@ class Move:
         ```python
         >>> move = rust_chess.Move(rust_chess.A2, rust_chess.A4)
         >>> move
+        Move(a2, a4, None)
+        >>> rust_chess.Move("a7a8q")
+        Move(a7, a8, QUEEN)
+        ```
+        """
+    def __reduce__(self) -> tuple[type, tuple]:
+        r"""
+        Reduce the move to its UCI string for pickling.
+        
+        ```python
+        >>> import pickle
+        >>> pickle.loads(pickle.dumps(rust_chess.Move("a7a8q")))
+        Move(a7, a8, QUEEN)
+        ```
+        """
+    def to_policy_index(self, board:Board) -> builtins.int:
+        r"""
+        Get the index of the move in the `AlphaZero` policy encoding (73 planes of 8 * 8 squares = 4672 indices).
+        Moves are encoded from the view of the side to move of the board, so for black the ranks are flipped
+        (a8 becomes a1, and moves towards rank 1 are north).
+        The index is `plane * 64 + source`, where `source` is the index of the flipped source square (a1 = 0, h8 = 63) and the planes are:
+        - 0-55: queen-like moves, as `direction * 7 + (distance - 1)` with directions N, NE, E, SE, S, SW, W, NW
+        - 56-63: knight moves, with offsets (rank, file) (2, 1), (1, 2), (-1, 2), (-2, 1), (-2, -1), (-1, -2), (1, -2), (2, -1)
+        - 64-72: underpromotions, as `piece * 3 + (file change + 1)` with pieces knight, bishop, rook
+        
+        Queen promotions are encoded as queen-like moves.
+        Raises a `ValueError` if the move can't be encoded (e.g. not a queen-like or knight move).
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> rust_chess.Move("e2e4").to_policy_index(board)
+        76
+        >>> rust_chess.Move("g1f3").to_policy_index(board)
+        4038
+        >>> rust_chess.Move("a7a8n").to_policy_index(board)
+        4208
+        >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+        >>> rust_chess.Move("e7e5").to_policy_index(board) # The same as e2e4 for white
+        76
+        >>> rust_chess.Move("a2a1n").to_policy_index(board) # The same as a7a8n for white
+        4208
+        ```
+        """
+    @staticmethod
+    def from_policy_index(index:builtins.int, board:Board) -> Move:
+        r"""
+        Create a new move from its index in the `AlphaZero` policy encoding (see `to_policy_index`).
+        The board is used for the side to move (the ranks are flipped for black) and to detect queen promotions.
+        Raises a `ValueError` if the index is out of range or the move would leave the board.
+        Doesn't check legality.
+        
+        ```python
+        >>> board = rust_chess.Board()
+        >>> rust_chess.Move.from_policy_index(76, board)
         Move(e2, e4, None)
+        >>> board = rust_chess.Board("8/P6k/8/8/8/8/8/K7 w - - 0 1")
+        >>> rust_chess.Move.from_policy_index(rust_chess.Move("a7a8n").to_policy_index(board), board)
+        Move(a7, a8, KNIGHT)
+        >>> rust_chess.Move.from_policy_index(rust_chess.Move("a7a8q").to_policy_index(board), board)
+        Move(a7, a8, QUEEN)
+        >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+        >>> rust_chess.Move.from_policy_index(76, board)
+        Move(e7, e5, None)
+        >>> moves = board.get_legal_moves()
+        >>> [rust_chess.Move.from_policy_index(move.to_policy_index(board), board) for move in moves] == moves
+        True
+        >>> board = rust_chess.Board("8/8/8/8/8/8/p6K/k7 b - - 0 1")
+        >>> rust_chess.Move.from_policy_index(rust_chess.Move("a2a1n").to_policy_index(board), board)
+        Move(a2, a1, KNIGHT)
+        >>> rust_chess.Move.from_policy_index(rust_chess.Move("a2a1q").to_policy_index(board), board)
+        Move(a2, a1, QUEEN)
+        ```
+        """
+    def is_promotion(self) -> builtins.bool:
+        r"""
+        Check if the move is a promotion.
+        
+        ```python
+        >>> rust_chess.Move("a7a8q").is_promotion()
+        True
+        >>> rust_chess.Move("e2e4").is_promotion()
+        False
         ```
         """
 
@@ -715,6 +2502,8 @@ class MoveGenerator:
     Move iterator class for generating legal moves.
     Not intended for direct use.
     Use the `Board` class methods for generating moves.
+    Generators are stateful one-shot iterators, so each move is only generated once.
+    Copy a generator to iterate over its remaining moves more than once.
     """
     def __iter__(self) -> MoveGenerator:
         r"""
@@ -724,16 +2513,104 @@ class MoveGenerator:
         r"""
         Get the next move in the generator
         """
+    def __len__(self) -> builtins.int:
+        r"""
+        Get the number of moves remaining in the generator.
+        Only counts moves allowed by the current iterator mask.
+        
+        ```python
+        >>> generator = rust_chess.Board().generate_legal_moves()
+        >>> len(generator)
+        20
+        >>> _ = next(generator), next(generator)
+        >>> len(generator)
+        18
+        >>> board = rust_chess.Board("8/P6k/8/8/8/8/8/K7 w - - 0 1")
+        >>> next(board.generate_legal_moves())
+        Move(a7, a8, QUEEN)
+        >>> len(board.generate_legal_captures())
+        0
+        >>> len(board.generate_legal_moves())
+        6
+        ```
+        """
+    def __copy__(self) -> MoveGenerator:
+        r"""
+        Create an independent copy of the generator (used by `copy.copy`).
+        The copy is a new generator for the same board with the same iterator mask and remaining moves,
+        so it generates the same moves as the original.
+        
+        ```python
+        >>> import copy
+        >>> board = rust_chess.Board("r3k3/8/8/8/8/8/3n4/R3K3 w - - 0 1")
+        >>> generator = board.generate_legal_captures()
+        >>> next(generator)
+        Move(a1, a8, None)
+        >>> generator_copy = copy.copy(generator)
+        >>> list(generator), list(generator_copy)
+        ([Move(e1, d2, None)], [Move(e1, d2, None)])
+        >>> generator = rust_chess.Board("8/P6k/8/8/8/8/8/K7 w - - 0 1").generate_legal_moves()
+        >>> next(generator)
+        Move(a7, a8, QUEEN)
+        >>> list(copy.copy(generator))[:3]
+        [Move(a7, a8, KNIGHT), Move(a7, a8, ROOK), Move(a7, a8, BISHOP)]
+        ```
+        """
+    def __deepcopy__(self, _memo:typing.Any) -> MoveGenerator:
+        r"""
+        Create an independent copy of the generator (used by `copy.deepcopy`).
+        Same as `__copy__`.
+        """
     def __repr__(self) -> builtins.str:
         r"""
         Get the type of the move generator
         """
 
+class Outcome:
+    r"""
+    Outcome class.
+    Represents the outcome of a finished game with the termination reason and the winner.
+    The winner is None for a draw.
+    
+    ```python
+    >>> outcome = rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").outcome()
+    >>> outcome
+    Outcome(CHECKMATE, BLACK)
+    >>> outcome.termination == rust_chess.Termination.CHECKMATE
+    True
+    >>> print(outcome.winner)
+    BLACK
+    >>> outcome.result()
+    '0-1'
+    ```
+    """
+    termination: Termination
+    r"""
+    Get the reason the game ended
+    """
+    winner: typing.Optional[Color]
+    r"""
+    Get the winner of the game, otherwise None for a draw
+    """
+    def result(self) -> builtins.str:
+        r"""
+        Get the result of the game as a string: "1-0", "0-1", or "1/2-1/2".
+        
+        ```python
+        >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").outcome().result()
+        '1/2-1/2'
+        ```
+        """
+    def __repr__(self) -> builtins.str:
+        r"""
+        Get the representation of the outcome (e.g. "Outcome(CHECKMATE, WHITE)").
+        """
+
 class Piece:
     r"""
     Piece class.
     Represents a chess piece with a type and color.
-    Uses the PieceType and Color classes.
+    Uses the `PieceType` and `Color` classes.
     Supports comparison and equality.
     A white piece is considered less than a black piece of the same type.
     
@@ -753,6 +2630,19 @@ class Piece:
         r"""
         Create a new piece from a piece type and color
         """
+    @staticmethod
+    def from_symbol(symbol:builtins.str) -> Piece:
+        r"""
+        Create a piece from its FEN letter.
+        Uppercase letters are white pieces and lowercase letters are black pieces.
+        
+        ```python
+        >>> rust_chess.Piece.from_symbol("Q") == rust_chess.Piece(rust_chess.QUEEN, rust_chess.WHITE)
+        True
+        >>> rust_chess.Piece.from_symbol("q")
+        q
+        ```
+        """
     def get_index(self) -> builtins.int:
         r"""
         Get the index of the piece (0-5)
@@ -761,6 +2651,17 @@ class Piece:
         r"""
         Convert the piece to a string
         """
+    def unicode_symbol(self) -> builtins.str:
+        r"""
+        Get the Unicode chess symbol of the piece.
+        
+        ```python
+        >>> rust_chess.Piece(rust_chess.KNIGHT, rust_chess.WHITE).unicode_symbol()
+        '♘'
+        >>> rust_chess.Piece(rust_chess.QUEEN, rust_chess.BLACK).unicode_symbol()
+        '♛'
+        ```
+        """
     def __str__(self) -> builtins.str:
         r"""
         Convert the piece to a string
@@ -795,6 +2696,30 @@ class PieceType:
     True
     ```
     """
+    @staticmethod
+    def from_index(index:builtins.int) -> PieceType:
+        r"""
+        Create a piece type from its index (0-5).
+        
+        ```python
+        >>> rust_chess.PieceType.from_index(4)
+        Q
+        >>> rust_chess.PieceType.from_index(4) == rust_chess.QUEEN
+        True
+        ```
+        """
+    @staticmethod
+    def from_symbol(symbol:builtins.str) -> PieceType:
+        r"""
+        Create a piece type from its letter (p, n, b, r, q, or k), case-insensitive.
+        
+        ```python
+        >>> rust_chess.PieceType.from_symbol("N") == rust_chess.KNIGHT
+        True
+        >>> rust_chess.PieceType.from_symbol("k")
+        K
+        ```
+        """
     def get_index(self) -> builtins.int:
         r"""
         Get the index of the piece.
@@ -805,6 +2730,28 @@ class PieceType:
         2
         ```
         """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Get the hash of the piece type, which is its index (0-5).
+        Allows piece types to be used as dictionary keys and in sets.
+        
+        ```python
+        >>> hash(rust_chess.BISHOP) == hash(2)
+        True
+        >>> {rust_chess.QUEEN: 900}[rust_chess.QUEEN]
+        900
+        ```
+        """
+    def __int__(self) -> builtins.int:
+        r"""
+        Get the index of the piece as an integer.
+        Useful for indexing tables by piece type.
+        
+        ```python
+        >>> int(rust_chess.QUEEN)
+        4
+        ```
+        """
     def get_string(self, color:Color=...) -> builtins.str:
         r"""
         Convert the piece to a string.
@@ -940,6 +2887,16 @@ class Square:
         e4
         ```
         """
+    def __reduce__(self) -> tuple[type, tuple]:
+        r"""
+        Reduce the square to its index for pickling.
+        
+        ```python
+        >>> import pickle
+        >>> pickle.loads(pickle.dumps(rust_chess.E4))
+        e4
+        ```
+        """
     def get_color(self) -> Color:
         r"""
         Get the color of the square on the chessboard
@@ -971,6 +2928,18 @@ class Square:
         True
         ```
         """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Get the hash of the square, which is its index (0-63).
+        Allows squares to be used as dictionary keys and in sets.
+        
+        ```python
+        >>> hash(rust_chess.E4) == hash(28)
+        True
+        >>> len({rust_chess.A1, rust_chess.Square("a1"), rust_chess.H8})
+        2
+        ```
+        """
     def get_rank(self) -> builtins.int:
         r"""
         Get the rank of the square as an integer (0-7).
@@ -1027,6 +2996,51 @@ class Square:
         True
         ```
         """
+    def mirror(self) -> Square:
+        r"""
+        Get the square with the rank flipped (e.g. a1 to a8).
+        Useful for looking up piece-square tables from black's perspective.
+        
+        ```python
+        >>> rust_chess.A1.mirror()
+        a8
+        >>> rust_chess.E4.mirror()
+        e5
+        ```
+        """
+    def mirror_horizontal(self) -> Square:
+        r"""
+        Get the square with the file flipped (e.g. a1 to h1).
+        
+        ```python
+        >>> rust_chess.H1.mirror_horizontal()
+        a1
+        >>> rust_chess.C6.mirror_horizontal()
+        f6
+        ```
+        """
+    def distance(self, other:Square) -> builtins.int:
+        r"""
+        Get the Chebyshev (king move) distance to another square.
+        
+        ```python
+        >>> rust_chess.A1.distance(rust_chess.H8)
+        7
+        >>> rust_chess.E4.distance(rust_chess.E5)
+        1
+        ```
+        """
+    def manhattan_distance(self, other:Square) -> builtins.int:
+        r"""
+        Get the Manhattan distance (files plus ranks apart) to another square.
+        
+        ```python
+        >>> rust_chess.A1.manhattan_distance(rust_chess.H8)
+        14
+        >>> rust_chess.E4.manhattan_distance(rust_chess.D5)
+        2
+        ```
+        """
 
 class BoardStatus(Enum):
     r"""
@@ -1043,3 +3057,165 @@ class BoardStatus(Enum):
     STALEMATE = ...
     CHECKMATE = ...
 
+class Direction(Enum):
+    r"""
+    Direction enum class.
+    Represents the eight directions a bitboard can be shifted in.
+    North is towards rank 8 and east is towards the h-file.
+    """
+    NORTH = ...
+    SOUTH = ...
+    EAST = ...
+    WEST = ...
+    NE = ...
+    NW = ...
+    SE = ...
+    SW = ...
+
+class Termination(Enum):
+    r"""
+    Termination enum class.
+    Represents the reason a game ended.
+    Supports comparison and equality.
+    """
+    CHECKMATE = ...
+    STALEMATE = ...
+    INSUFFICIENT_MATERIAL = ...
+    SEVENTYFIVE_MOVES = ...
+    FIVEFOLD_REPETITION = ...
+    FIFTY_MOVES = ...
+    THREEFOLD_REPETITION = ...
+
+def between(a:Square, b:Square) -> Bitboard:    r"""
+    Get the bitboard of the squares strictly between two squares on the same rank, file, or diagonal.
+    Empty if the squares are not aligned.
+    
+    ```python
+    >>> list(rust_chess.between(rust_chess.A1, rust_chess.A4))
+    [a2, a3]
+    >>> rust_chess.between(rust_chess.A1, rust_chess.B3).popcnt()
+    0
+    ```
+    """
+
+def bishop_attacks(square:Square, blockers:Bitboard) -> Bitboard:    r"""
+    Get the bitboard of the squares a bishop on a square attacks.
+    The attacks stop at (and include) the first blocker in each direction.
+    
+    ```python
+    >>> rust_chess.bishop_attacks(rust_chess.D4, rust_chess.Bitboard(0)).popcnt()
+    13
+    >>> list(rust_chess.bishop_attacks(rust_chess.A1, rust_chess.Bitboard(rust_chess.C3)))
+    [b2, c3]
+    ```
+    """
+
+def is_mate_score(score:builtins.int) -> builtins.bool:    r"""
+    Check if a score is a mate score (for either side).
+    Scores beyond the score of an immediate checkmate are not mate scores.
+    
+    ```python
+    >>> rust_chess.is_mate_score(rust_chess.mate_score(5))
+    True
+    >>> rust_chess.is_mate_score(-rust_chess.mate_score(4))
+    True
+    >>> rust_chess.is_mate_score(350)
+    False
+    >>> rust_chess.is_mate_score(-2**31)
+    False
+    ```
+    """
+
+def king_attacks(square:Square) -> Bitboard:    r"""
+    Get the bitboard of the squares a king on a square attacks.
+    
+    ```python
+    >>> rust_chess.king_attacks(rust_chess.E4).popcnt()
+    8
+    ```
+    """
+
+def knight_attacks(square:Square) -> Bitboard:    r"""
+    Get the bitboard of the squares a knight on a square attacks.
+    
+    ```python
+    >>> list(rust_chess.knight_attacks(rust_chess.A1))
+    [c2, b3]
+    ```
+    """
+
+def line(a:Square, b:Square) -> Bitboard:    r"""
+    Get the bitboard of the whole rank, file, or diagonal through two squares (edge to edge).
+    Empty if the squares are not aligned.
+    
+    ```python
+    >>> list(rust_chess.line(rust_chess.C3, rust_chess.F6))
+    [a1, b2, c3, d4, e5, f6, g7, h8]
+    >>> rust_chess.line(rust_chess.A1, rust_chess.B3).popcnt()
+    0
+    ```
+    """
+
+def mate_in(score:builtins.int) -> builtins.int:    r"""
+    Get the number of moves until checkmate from a mate score.
+    Positive if delivering checkmate and negative if getting checkmated.
+    Raises a `ValueError` if the score is not a mate score.
+    
+    ```python
+    >>> rust_chess.mate_in(rust_chess.mate_score(5))
+    3
+    >>> rust_chess.mate_in(-rust_chess.mate_score(4))
+    -2
+    >>> rust_chess.mate_in(-2**31)
+    Traceback (most recent call last):
+    ...
+    ValueError: Score is not a mate score
+    ```
+    """
+
+def mate_score(plies:builtins.int) -> builtins.int:    r"""
+    Get the score for delivering checkmate in a number of plies (half-moves).
+    Faster checkmates get higher scores. Negate the score for getting checkmated.
+    Raises a `ValueError` if the number of plies is negative or too large for a mate score.
+    
+    ```python
+    >>> rust_chess.mate_score(5)
+    99995
+    >>> rust_chess.mate_score(-1)
+    Traceback (most recent call last):
+    ...
+    ValueError: Plies must be from 0 to 999 (found -1)
+    ```
+    """
+
+def pawn_attacks(square:Square, color:Color) -> Bitboard:    r"""
+    Get the bitboard of the squares a pawn of a certain color on a square attacks.
+    
+    ```python
+    >>> list(rust_chess.pawn_attacks(rust_chess.E4, rust_chess.WHITE))
+    [d5, f5]
+    >>> list(rust_chess.pawn_attacks(rust_chess.A7, rust_chess.BLACK))
+    [b6]
+    ```
+    """
+
+def queen_attacks(square:Square, blockers:Bitboard) -> Bitboard:    r"""
+    Get the bitboard of the squares a queen on a square attacks.
+    The attacks stop at (and include) the first blocker in each direction.
+    
+    ```python
+    >>> rust_chess.queen_attacks(rust_chess.D4, rust_chess.Bitboard(0)).popcnt()
+    27
+    ```
+    """
+
+def rook_attacks(square:Square, blockers:Bitboard) -> Bitboard:    r"""
+    Get the bitboard of the squares a rook on a square attacks.
+    The attacks stop at (and include) the first blocker in each direction.
+    
+    ```python
+    >>> rust_chess.rook_attacks(rust_chess.A1, rust_chess.Bitboard(0)).popcnt()
+    14
+    ```
+    """
+
@@ -0,0 +1,122 @@
+use pyo3::prelude::*;
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+
+use crate::types::{bitboard::PyBitboard, color::PyColor, square::PySquare};
+
+/// Get the bitboard of the squares a knight on a square attacks.
+///
+/// ```python
+/// >>> list(rust_chess.knight_attacks(rust_chess.A1))
+/// [c2, b3]
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn knight_attacks(square: PySquare) -> PyBitboard {
+    PyBitboard(chess::get_knight_moves(square.0))
+}
+
+/// Get the bitboard of the squares a king on a square attacks.
+///
+/// ```python
+/// >>> rust_chess.king_attacks(rust_chess.E4).popcnt()
+/// 8
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn king_attacks(square: PySquare) -> PyBitboard {
+    PyBitboard(chess::get_king_moves(square.0))
+}
+
+/// Get the bitboard of the squares a pawn of a certain color on a square attacks.
+///
+/// ```python
+/// >>> list(rust_chess.pawn_attacks(rust_chess.E4, rust_chess.WHITE))
+/// [d5, f5]
+/// >>> list(rust_chess.pawn_attacks(rust_chess.A7, rust_chess.BLACK))
+/// [b6]
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn pawn_attacks(square: PySquare, color: PyColor) -> PyBitboard {
+    // Use every square as a target, so all attacked squares are included
+    PyBitboard(chess::get_pawn_attacks(square.0, color.0, !chess::EMPTY))
+}
+
+/// Get the bitboard of the squares a bishop on a square attacks.
+/// The attacks stop at (and include) the first blocker in each direction.
+///
+/// ```python
+/// >>> rust_chess.bishop_attacks(rust_chess.D4, rust_chess.Bitboard(0)).popcnt()
+/// 13
+/// >>> list(rust_chess.bishop_attacks(rust_chess.A1, rust_chess.Bitboard(rust_chess.C3)))
+/// [b2, c3]
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn bishop_attacks(square: PySquare, blockers: PyBitboard) -> PyBitboard {
+    PyBitboard(chess::get_bishop_moves(square.0, blockers.0))
+}
+
+/// Get the bitboard of the squares a rook on a square attacks.
+/// The attacks stop at (and include) the first blocker in each direction.
+///
+/// ```python
+/// >>> rust_chess.rook_attacks(rust_chess.A1, rust_chess.Bitboard(0)).popcnt()
+/// 14
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn rook_attacks(square: PySquare, blockers: PyBitboard) -> PyBitboard {
+    PyBitboard(chess::get_rook_moves(square.0, blockers.0))
+}
+
+/// Get the bitboard of the squares a queen on a square attacks.
+/// The attacks stop at (and include) the first blocker in each direction.
+///
+/// ```python
+/// >>> rust_chess.queen_attacks(rust_chess.D4, rust_chess.Bitboard(0)).popcnt()
+/// 27
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn queen_attacks(square: PySquare, blockers: PyBitboard) -> PyBitboard {
+    PyBitboard(chess::get_bishop_moves(square.0, blockers.0) | chess::get_rook_moves(square.0, blockers.0))
+}
+
+/// Get the bitboard of the squares strictly between two squares on the same rank, file, or diagonal.
+/// Empty if the squares are not aligned.
+///
+/// ```python
+/// >>> list(rust_chess.between(rust_chess.A1, rust_chess.A4))
+/// [a2, a3]
+/// >>> rust_chess.between(rust_chess.A1, rust_chess.B3).popcnt()
+/// 0
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn between(a: PySquare, b: PySquare) -> PyBitboard {
+    PyBitboard(chess::between(a.0, b.0))
+}
+
+/// Get the bitboard of the whole rank, file, or diagonal through two squares (edge to edge).
+/// Empty if the squares are not aligned.
+///
+/// ```python
+/// >>> list(rust_chess.line(rust_chess.C3, rust_chess.F6))
+/// [a1, b2, c3, d4, e5, f6, g7, h8]
+/// >>> rust_chess.line(rust_chess.A1, rust_chess.B3).popcnt()
+/// 0
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn line(a: PySquare, b: PySquare) -> PyBitboard {
+    PyBitboard(chess::line(a.0, b.0))
+}
@@ -6,11 +6,20 @@
 use pyo3::prelude::*;
 use pyo3_stub_gen::{define_stub_info_gatherer, module_variable};
 
+mod attacks;
+mod random;
+mod score;
 mod types;
 
+use crate::attacks::{
+    between, bishop_attacks, king_attacks, knight_attacks, line, pawn_attacks, queen_attacks,
+    rook_attacks,
+};
+use crate::score::{is_mate_score, mate_in, mate_score};
 use crate::types::{
-    bitboard::PyBitboard,
-    board::{PyBoard, PyBoardStatus},
+    bitboard::{PyBitboard, PyDirection},
+    board::{PyBoard, PyBoardStatus, PyOutcome, PyTermination},
+    board_builder::PyBoardBuilder,
     color::{PyColor, BLACK, COLORS, WHITE},
     piece::{PyPiece, PyPieceType, BISHOP, KING, KNIGHT, PAWN, PIECES, QUEEN, ROOK},
     r#move::{PyMove, PyMoveGenerator},
@@ -26,11 +35,28 @@ fn rust_chess(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PyPieceType>()?;
     module.add_class::<PyPiece>()?;
     module.add_class::<PyBitboard>()?;
+    module.add_class::<PyDirection>()?;
     module.add_class::<PySquare>()?;
     module.add_class::<PyMove>()?;
     module.add_class::<PyMoveGenerator>()?;
     module.add_class::<PyBoardStatus>()?;
+    module.add_class::<PyTermination>()?;
+    module.add_class::<PyOutcome>()?;
     module.add_class::<PyBoard>()?;
+    module.add_class::<PyBoardBuilder>()?;
+
+    // Add the functions to the module
+    module.add_function(wrap_pyfunction!(mate_score, module)?)?;
+    module.add_function(wrap_pyfunction!(is_mate_score, module)?)?;
+    module.add_function(wrap_pyfunction!(mate_in, module)?)?;
+    module.add_function(wrap_pyfunction!(knight_attacks, module)?)?;
+    module.add_function(wrap_pyfunction!(king_attacks, module)?)?;
+    module.add_function(wrap_pyfunction!(pawn_attacks, module)?)?;
+    module.add_function(wrap_pyfunction!(bishop_attacks, module)?)?;
+    module.add_function(wrap_pyfunction!(rook_attacks, module)?)?;
+    module.add_function(wrap_pyfunction!(queen_attacks, module)?)?;
+    module.add_function(wrap_pyfunction!(between, module)?)?;
+    module.add_function(wrap_pyfunction!(line, module)?)?;
 
     // Add the constants and stubs to the module
 
@@ -58,6 +84,41 @@ fn rust_chess(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add("PIECES", PIECES)?;
     module_variable!("rust_chess", "PIECES", Vec<PyPieceType>);
 
+    // Add the list of all squares (A1 to H8 in index order) and its stub
+    module.add("SQUARES", chess::ALL_SQUARES.map(PySquare))?;
+    module_variable!("rust_chess", "SQUARES", Vec<PySquare>);
+
+    // Define a macro to add bitboard constants and stubs directly to the module (e.g. RANK_1, FILE_A, etc.)
+    macro_rules! add_bitboard_constants {
+        ($module:expr, $($name:ident = $bitboard:expr),*) => {
+            $(
+                $module.add(stringify!($name), PyBitboard($bitboard))?;
+                module_variable!("rust_chess", stringify!($name), PyBitboard);
+            )*
+        }
+    }
+
+    // Add the rank and file bitboard constants
+    #[rustfmt::skip]
+    add_bitboard_constants!(module,
+        RANK_1 = chess::get_rank(chess::Rank::First),
+        RANK_2 = chess::get_rank(chess::Rank::Second),
+        RANK_3 = chess::get_rank(chess::Rank::Third),
+        RANK_4 = chess::get_rank(chess::Rank::Fourth),
+        RANK_5 = chess::get_rank(chess::Rank::Fifth),
+        RANK_6 = chess::get_rank(chess::Rank::Sixth),
+        RANK_7 = chess::get_rank(chess::Rank::Seventh),
+        RANK_8 = chess::get_rank(chess::Rank::Eighth),
+        FILE_A = chess::get_file(chess::File::A),
+        FILE_B = chess::get_file(chess::File::B),
+        FILE_C = chess::get_file(chess::File::C),
+        FILE_D = chess::get_file(chess::File::D),
+        FILE_E = chess::get_file(chess::File::E),
+        FILE_F = chess::get_file(chess::File::F),
+        FILE_G = chess::get_file(chess::File::G),
+        FILE_H = chess::get_file(chess::File::H)
+    );
+
     // Define a macro to add square constants and stubs directly to the module (e.g. A1, A2, etc.)
     macro_rules! add_square_constants {
         ($module:expr, $($name:ident),*) => {
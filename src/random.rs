@@ -0,0 +1,36 @@
+use std::hash::{BuildHasher, Hasher};
+
+/// Small pseudorandom number generator (`SplitMix64`) for picking random moves.
+/// Not suitable for cryptography.
+///
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Create a new generator from a seed, otherwise from a random seed.
+    ///
+    #[inline]
+    pub(crate) fn new(seed: Option<u64>) -> Self {
+        // The standard library randomly seeds each hasher, so use one as a source of random seeds
+        Rng(seed.unwrap_or_else(|| std::collections::hash_map::RandomState::new().build_hasher().finish()))
+    }
+
+    /// Get the next random 64-bit number.
+    ///
+    #[inline]
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Get a random index less than a length (which must be greater than 0).
+    ///
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)] // The result is less than the length
+    pub(crate) fn index(&mut self, len: usize) -> usize {
+        // Multiply and shift instead of using the remainder to avoid bias
+        ((u128::from(self.next_u64()) * len as u128) >> 64) as usize
+    }
+}
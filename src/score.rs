@@ -0,0 +1,85 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+
+// Score of a checkmate on the board, reduced by one for each ply until the checkmate
+const MATE_SCORE: i32 = 100_000;
+
+// Maximum number of plies until a checkmate for a score to count as a mate score
+const MAX_MATE_PLY: i32 = 1000;
+
+/// Get the score for delivering checkmate in a number of plies (half-moves).
+/// Faster checkmates get higher scores. Negate the score for getting checkmated.
+/// Raises a `ValueError` if the number of plies is negative or too large for a mate score.
+///
+/// ```python
+/// >>> rust_chess.mate_score(5)
+/// 99995
+/// >>> rust_chess.mate_score(-1)
+/// Traceback (most recent call last):
+/// ...
+/// ValueError: Plies must be from 0 to 999 (found -1)
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn mate_score(plies: i32) -> PyResult<i32> {
+    // Keep the score in the mate score range, which also keeps the subtraction from overflowing
+    if !(0..MAX_MATE_PLY).contains(&plies) {
+        return Err(PyValueError::new_err(format!(
+            "Plies must be from 0 to {} (found {plies})",
+            MAX_MATE_PLY - 1
+        )));
+    }
+
+    Ok(MATE_SCORE - plies)
+}
+
+/// Check if a score is a mate score (for either side).
+/// Scores beyond the score of an immediate checkmate are not mate scores.
+///
+/// ```python
+/// >>> rust_chess.is_mate_score(rust_chess.mate_score(5))
+/// True
+/// >>> rust_chess.is_mate_score(-rust_chess.mate_score(4))
+/// True
+/// >>> rust_chess.is_mate_score(350)
+/// False
+/// >>> rust_chess.is_mate_score(-2**31)
+/// False
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn is_mate_score(score: i32) -> bool {
+    // Use the unsigned absolute value, since the absolute value of i32::MIN overflows
+    let distance = score.unsigned_abs();
+    distance > (MATE_SCORE - MAX_MATE_PLY).unsigned_abs() && distance <= MATE_SCORE.unsigned_abs()
+}
+
+/// Get the number of moves until checkmate from a mate score.
+/// Positive if delivering checkmate and negative if getting checkmated.
+/// Raises a `ValueError` if the score is not a mate score.
+///
+/// ```python
+/// >>> rust_chess.mate_in(rust_chess.mate_score(5))
+/// 3
+/// >>> rust_chess.mate_in(-rust_chess.mate_score(4))
+/// -2
+/// >>> rust_chess.mate_in(-2**31)
+/// Traceback (most recent call last):
+/// ...
+/// ValueError: Score is not a mate score
+/// ```
+#[gen_stub_pyfunction]
+#[pyfunction]
+#[inline]
+pub(crate) fn mate_in(score: i32) -> PyResult<i32> {
+    if !is_mate_score(score) {
+        return Err(PyValueError::new_err("Score is not a mate score"));
+    }
+
+    // Round up, since the last move of a checkmate is made by the side delivering it
+    // (the absolute value can't overflow, since mate scores are in range)
+    let plies = MATE_SCORE - score.abs();
+    Ok(score.signum() * ((plies + 1) / 2))
+}
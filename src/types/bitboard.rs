@@ -1,8 +1,38 @@
 use pyo3::{exceptions::PyValueError, prelude::*, types::PyAny};
-use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyclass_enum, gen_stub_pymethods};
 
 use crate::types::square::PySquare;
 
+// File masks used to prevent shifts from wrapping around the board
+const FILE_A: u64 = 0x0101_0101_0101_0101;
+const FILE_H: u64 = 0x8080_8080_8080_8080;
+
+/// Direction enum class.
+/// Represents the eight directions a bitboard can be shifted in.
+/// North is towards rank 8 and east is towards the h-file.
+///
+#[gen_stub_pyclass_enum]
+#[pyclass(name = "Direction", frozen, eq)]
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum PyDirection {
+    #[pyo3(name = "NORTH")]
+    North,
+    #[pyo3(name = "SOUTH")]
+    South,
+    #[pyo3(name = "EAST")]
+    East,
+    #[pyo3(name = "WEST")]
+    West,
+    #[pyo3(name = "NE")]
+    NorthEast,
+    #[pyo3(name = "NW")]
+    NorthWest,
+    #[pyo3(name = "SE")]
+    SouthEast,
+    #[pyo3(name = "SW")]
+    SouthWest,
+}
+
 /// Bitboard class.
 /// Represents a 64-bit unsigned integer.
 /// Each bit represents a square on the chessboard.
@@ -49,10 +79,50 @@ impl PyBitboard {
 
     /// Convert the Bitboard to a square.
     /// This grabs the least-significant square.
+    /// Raises a `ValueError` if the Bitboard is empty (use `lsb` to get None instead).
+    ///
+    /// ```python
+    /// >>> rust_chess.Bitboard(rust_chess.E4).to_square()
+    /// e4
+    /// >>> rust_chess.Bitboard(0).to_square()
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Bitboard is empty
+    /// ```
+    #[inline]
+    fn to_square(&self) -> PyResult<PySquare> {
+        self.lsb()
+            .ok_or_else(|| PyValueError::new_err("Bitboard is empty"))
+    }
+
+    /// Get the least-significant square of the Bitboard, otherwise None if it is empty.
+    ///
+    /// ```python
+    /// >>> (rust_chess.C3.to_bitboard() | rust_chess.F6.to_bitboard()).lsb()
+    /// c3
+    /// >>> rust_chess.Bitboard(0).lsb()
+    ///
+    /// ```
+    #[inline]
+    fn lsb(&self) -> Option<PySquare> {
+        (self.0 != chess::EMPTY).then(|| PySquare(self.0.to_square()))
+    }
+
+    /// Get the most-significant square of the Bitboard, otherwise None if it is empty.
     ///
+    /// ```python
+    /// >>> (rust_chess.C3.to_bitboard() | rust_chess.F6.to_bitboard()).msb()
+    /// f6
+    /// >>> rust_chess.Bitboard(0).msb()
+    ///
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
     #[inline]
-    fn to_square(&self) -> PySquare {
-        PySquare(self.0.to_square())
+    fn msb(&self) -> Option<PySquare> {
+        (self.0 != chess::EMPTY).then(|| {
+            // The highest set bit is at index 63 minus the number of leading zeros
+            PySquare(unsafe { chess::Square::new(63 - self.0 .0.leading_zeros() as u8) })
+        })
     }
 
     /// Convert the Bitboard to an unsigned 64-bit integer
@@ -100,6 +170,45 @@ impl PyBitboard {
         self.0.popcnt()
     }
 
+    /// Get the number of squares in the Bitboard.
+    ///
+    /// ```python
+    /// >>> len(rust_chess.Bitboard(0))
+    /// 0
+    /// >>> len(rust_chess.Bitboard(0xFF))
+    /// 8
+    /// ```
+    #[inline]
+    fn __len__(&self) -> usize {
+        self.popcnt() as usize
+    }
+
+    /// Check if the Bitboard has any squares.
+    ///
+    /// ```python
+    /// >>> bool(rust_chess.Bitboard(0))
+    /// False
+    /// >>> bool(rust_chess.Bitboard(rust_chess.E4))
+    /// True
+    /// ```
+    #[inline]
+    fn __bool__(&self) -> bool {
+        self.0 != chess::EMPTY
+    }
+
+    /// Check if a square is in the Bitboard.
+    ///
+    /// ```python
+    /// >>> rust_chess.A1 in rust_chess.Bitboard.from_square(rust_chess.A1)
+    /// True
+    /// >>> rust_chess.A2 in rust_chess.Bitboard.from_square(rust_chess.A1)
+    /// False
+    /// ```
+    #[inline]
+    fn __contains__(&self, square: PySquare) -> bool {
+        self.0 & chess::BitBoard::from_square(square.0) != chess::EMPTY
+    }
+
     /// Flip a bitboard vertically.
     /// View it from the opponent's perspective.
     /// Useful for operations that rely on symmetry, like piece-square tables.
@@ -109,10 +218,68 @@ impl PyBitboard {
         PyBitboard(self.0.reverse_colors())
     }
 
-    /// Return an iterator of the bitboard
+    /// Mirror a bitboard horizontally (swap the a-file and h-file, etc.).
+    ///
+    /// ```python
+    /// >>> list((rust_chess.B1.to_bitboard() | rust_chess.C6.to_bitboard()).mirror_horizontal())
+    /// [g1, f6]
+    /// ```
+    #[inline]
+    fn mirror_horizontal(&self) -> Self {
+        // Reversing the bits flips both ways, so swap the bytes (ranks) back
+        PyBitboard::from_uint(self.0 .0.reverse_bits().swap_bytes())
+    }
+
+    /// Rotate a bitboard by 180 degrees (flip it both vertically and horizontally).
+    ///
+    /// ```python
+    /// >>> list((rust_chess.B1.to_bitboard() | rust_chess.C6.to_bitboard()).rotate_180())
+    /// [f3, g8]
+    /// >>> bitboard = rust_chess.Bitboard(0x1234_5678_9ABC_DEF0)
+    /// >>> bitboard.rotate_180().rotate_180() == bitboard
+    /// True
+    /// ```
+    #[inline]
+    fn rotate_180(&self) -> Self {
+        PyBitboard::from_uint(self.0 .0.reverse_bits())
+    }
+
+    /// Shift the Bitboard one square in a direction.
+    /// Squares shifted off the edge of the board are removed (they don't wrap around).
+    ///
+    /// ```python
+    /// >>> list(rust_chess.Bitboard(rust_chess.E4).shift_dir(rust_chess.Direction.NE))
+    /// [f5]
+    /// >>> rust_chess.Bitboard(rust_chess.H4).shift_dir(rust_chess.Direction.EAST).popcnt()
+    /// 0
+    /// ```
+    #[inline]
+    fn shift_dir(&self, direction: PyDirection) -> Self {
+        let bits = self.0 .0;
+        PyBitboard::from_uint(match direction {
+            PyDirection::North => bits << 8,
+            PyDirection::South => bits >> 8,
+            PyDirection::East => (bits << 1) & !FILE_A,
+            PyDirection::West => (bits >> 1) & !FILE_H,
+            PyDirection::NorthEast => (bits << 9) & !FILE_A,
+            PyDirection::NorthWest => (bits << 7) & !FILE_H,
+            PyDirection::SouthEast => (bits >> 7) & !FILE_A,
+            PyDirection::SouthWest => (bits >> 9) & !FILE_H,
+        })
+    }
+
+    /// Return an iterator of the bitboard.
+    /// Iterates over a copy, so the bitboard itself (e.g. a module constant) is not emptied.
+    ///
+    /// ```python
+    /// >>> len(list(rust_chess.RANK_1))
+    /// 8
+    /// >>> rust_chess.RANK_1.popcnt()
+    /// 8
+    /// ```
     #[inline]
-    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
-        slf
+    fn __iter__(&self) -> Self {
+        *self
     }
 
     /// Get the next square in the Bitboard.
@@ -1,12 +1,28 @@
-use std::str::FromStr;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    str::FromStr,
+    sync::OnceLock,
+};
 
-use pyo3::{exceptions::PyValueError, prelude::*};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyList};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyclass_enum, gen_stub_pymethods};
+use rayon::prelude::*;
 
+use crate::random::Rng;
 use crate::types::{
-    bitboard::PyBitboard, color::PyColor, r#move::{PyMove, PyMoveGenerator}, piece::{PAWN, PyPiece, PyPieceType}, square::PySquare
+    bitboard::PyBitboard, color::{BLACK, PyColor, WHITE}, r#move::{PyMove, PyMoveGenerator}, piece::{PAWN, PIECE_VALUES, PyPiece, PyPieceType}, square::PySquare
 };
 
+// Move ordering bonus for moves that give check
+const CHECK_SORT_BONUS: i32 = 500;
+
+// Move sort key penalty for captures that lose material, so they rank below non-capturing moves
+const LOSING_CAPTURE_SORT_PENALTY: i32 = 100_000;
+
+// FEN string of the starting position
+const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
 /// Board status enum class.
 /// Represents the status of a chess board.
 /// The status can be one of the following:
@@ -31,21 +47,129 @@ pub(crate) enum PyBoardStatus {
     Checkmate,
 }
 
+/// Termination enum class.
+/// Represents the reason a game ended.
+/// Supports comparison and equality.
+///
+#[gen_stub_pyclass_enum]
+#[pyclass(name = "Termination", frozen, eq, ord)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub(crate) enum PyTermination {
+    #[pyo3(name = "CHECKMATE")]
+    Checkmate,
+    #[pyo3(name = "STALEMATE")]
+    Stalemate,
+    #[pyo3(name = "INSUFFICIENT_MATERIAL")]
+    InsufficientMaterial,
+    #[pyo3(name = "SEVENTYFIVE_MOVES")]
+    SeventyFiveMoves,
+    #[pyo3(name = "FIVEFOLD_REPETITION")]
+    FiveFoldRepetition,
+    #[pyo3(name = "FIFTY_MOVES")]
+    FiftyMoves,
+    #[pyo3(name = "THREEFOLD_REPETITION")]
+    ThreeFoldRepetition,
+}
+
+/// Outcome class.
+/// Represents the outcome of a finished game with the termination reason and the winner.
+/// The winner is None for a draw.
+///
+/// ```python
+/// >>> outcome = rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").outcome()
+/// >>> outcome
+/// Outcome(CHECKMATE, BLACK)
+/// >>> outcome.termination == rust_chess.Termination.CHECKMATE
+/// True
+/// >>> print(outcome.winner)
+/// BLACK
+/// >>> outcome.result()
+/// '0-1'
+/// ```
+#[gen_stub_pyclass]
+#[pyclass(name = "Outcome", frozen, eq)]
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) struct PyOutcome {
+    /// Get the reason the game ended
+    #[pyo3(get)]
+    termination: PyTermination,
+    /// Get the winner of the game, otherwise None for a draw
+    #[pyo3(get)]
+    winner: Option<PyColor>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyOutcome {
+    /// Get the result of the game as a string: "1-0", "0-1", or "1/2-1/2".
+    ///
+    /// ```python
+    /// >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").outcome().result()
+    /// '1/2-1/2'
+    /// ```
+    #[inline]
+    fn result(&self) -> &'static str {
+        match self.winner {
+            Some(PyColor(chess::Color::White)) => "1-0",
+            Some(PyColor(chess::Color::Black)) => "0-1",
+            None => "1/2-1/2",
+        }
+    }
+
+    /// Get the representation of the outcome (e.g. "Outcome(CHECKMATE, WHITE)").
+    #[inline]
+    fn __repr__(&self) -> String {
+        let termination = match self.termination {
+            PyTermination::Checkmate => "CHECKMATE",
+            PyTermination::Stalemate => "STALEMATE",
+            PyTermination::InsufficientMaterial => "INSUFFICIENT_MATERIAL",
+            PyTermination::SeventyFiveMoves => "SEVENTYFIVE_MOVES",
+            PyTermination::FiveFoldRepetition => "FIVEFOLD_REPETITION",
+            PyTermination::FiftyMoves => "FIFTY_MOVES",
+            PyTermination::ThreeFoldRepetition => "THREEFOLD_REPETITION",
+        };
+        let winner = match self.winner {
+            Some(PyColor(chess::Color::White)) => "WHITE",
+            Some(PyColor(chess::Color::Black)) => "BLACK",
+            None => "None",
+        };
+
+        format!("Outcome({termination}, {winner})")
+    }
+}
+
+/// A previous state of the board.
+/// Stored on the move stack for undoing moves and detecting repetitions.
+///
+#[derive(Clone, Copy)]
+struct BoardState {
+    board: chess::Board,
+    chess_move: PyMove, // The move made from this state (null moves are stored as the default move)
+    castling_rooks: chess::BitBoard,
+    halfmove_clock: u8,
+    fullmove_number: u16,
+}
+
 /// Board class.
 /// Represents the state of a chess board.
 ///
 #[gen_stub_pyclass]
-#[pyclass(name = "Board")]
+#[pyclass(name = "Board", module = "rust_chess")]
 pub(crate) struct PyBoard {
-    board: chess::Board,
+    pub(crate) board: chess::Board,
     // move_gen: chess::MoveGen,
     move_gen: Py<PyMoveGenerator>, // Use a Py to be able to share between Python and Rust
 
     /// Get the halfmove clock.
+    /// Stops increasing at 255 instead of overflowing.
     ///
     /// ```python
     /// >>> rust_chess.Board().halfmove_clock
     /// 0
+    /// >>> board = rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 255 1")
+    /// >>> board.make_move(rust_chess.Move("e1e2"))
+    /// >>> board.halfmove_clock
+    /// 255
     /// ```
     #[pyo3(get)]
     halfmove_clock: u8, // Halfmoves since last pawn move or capture
@@ -57,7 +181,22 @@ pub(crate) struct PyBoard {
     /// 1
     /// ```
     #[pyo3(get)]
-    fullmove_number: u8, // Fullmove number (increments after black moves)
+    fullmove_number: u16, // Fullmove number (increments after black moves)
+
+    /// Check if the board is in Chess960 (Fischer Random) mode.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().chess960
+    /// False
+    /// ```
+    #[pyo3(get)]
+    chess960: bool,
+
+    castling_rooks: chess::BitBoard, // Rooks with castling rights in Chess960 mode (the chess crate only supports standard castling)
+
+    move_stack: Vec<BoardState>, // Previous states of the board (for undoing moves and repetition detection)
+
+    status: OnceLock<PyBoardStatus>, // Cached status of the board (computed on first use and reset whenever the board changes)
 }
 // TODO: Incremental Zobrist hash
 
@@ -65,6 +204,7 @@ pub(crate) struct PyBoard {
 #[pymethods]
 impl PyBoard {
     /// Create a new board from a FEN string, otherwise default to the starting position.
+    /// If `chess960` is True, the board is in Chess960 mode (see `from_fen`).
     ///
     /// ```python
     /// >>> rust_chess.Board()
@@ -73,28 +213,33 @@ impl PyBoard {
     /// rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2
     /// ```
     #[new]
-    #[pyo3(signature = (fen = None))] // Default to None
-    fn new(fen: Option<&str>) -> PyResult<Self> {
+    #[pyo3(signature = (fen = None, chess960 = false))] // Default to None
+    fn new(fen: Option<&str>, chess960: bool) -> PyResult<Self> {
         match fen {
             // If no FEN string is provided, use the default starting position
-            None => {
+            None if !chess960 => {
                 let board = chess::Board::default();
 
                 // We can assume the GIL is acquired, since this function is only called from Python
                 let py = unsafe { Python::assume_gil_acquired() };
 
                 // Create a new move generator using the chess crate
-                let move_gen = Py::new(py, PyMoveGenerator(chess::MoveGen::new_legal(&board)))?;
+                let move_gen = Py::new(py, PyMoveGenerator::new(&board))?;
 
                 Ok(PyBoard {
                     board,
                     move_gen,
                     halfmove_clock: 0,
                     fullmove_number: 1,
+                    chess960: false,
+                    castling_rooks: chess::EMPTY,
+                    move_stack: Vec::new(),
+                    status: OnceLock::new(),
                 })
             }
+            None => PyBoard::from_fen(STARTING_FEN, chess960),
             // Otherwise, parse the FEN string using the chess crate
-            Some(fen_str) => PyBoard::from_fen(fen_str),
+            Some(fen_str) => PyBoard::from_fen(fen_str, chess960),
         }
     }
 
@@ -106,18 +251,7 @@ impl PyBoard {
     /// ```
     #[inline]
     fn get_fen(&self) -> String {
-        let base_fen = self.board.to_string();
-
-        // 0: board, 1: player, 2: castling, 3: en passant, 4: halfmove clock, 5: fullmove number
-        let mut parts: Vec<&str> = base_fen.split_whitespace().collect();
-
-        // The chess crate does not track the halfmove clock and fullmove number correctly, so we need to add them manually.
-        let halfmove_clock_str: String = self.halfmove_clock.to_string();
-        let fullmove_number_str: String = self.fullmove_number.to_string();
-        parts[4] = halfmove_clock_str.as_str();
-        parts[5] = fullmove_number_str.as_str();
-
-        parts.join(" ")
+        self.format_fen_with_castling(&self.board, self.halfmove_clock, self.fullmove_number, self.castling_rooks)
     }
 
     /// Get the FEN string representation of the board.
@@ -142,537 +276,3491 @@ impl PyBoard {
         self.get_fen()
     }
 
-    /// Create a new board from a FEN string.
+    /// Check if a color has kingside castling rights.
+    /// Doesn't check if castling is currently possible.
     ///
     /// ```python
-    /// >>> rust_chess.Board.from_fen("rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2")
-    /// rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2
+    /// >>> rust_chess.Board().has_kingside_castle_rights(rust_chess.BLACK)
+    /// True
+    /// >>> rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w Qkq - 0 1").has_kingside_castle_rights(rust_chess.WHITE)
+    /// False
     /// ```
-    #[staticmethod]
-    fn from_fen(fen: &str) -> PyResult<Self> {
-        // Extract the halfmove clock and fullmove number from the FEN string
-        let parts: Vec<&str> = fen.split_whitespace().collect();
-        if parts.len() != 6 {
-            return Err(PyValueError::new_err(
-                "FEN string must have exactly 6 parts",
-            ));
+    #[inline]
+    fn has_kingside_castle_rights(&self, color: PyColor) -> bool {
+        if self.chess960 {
+            let king_file = self.board.king_square(color.0).get_file();
+            return self.get_chess960_castling_rooks(color.0).any(|rook| rook.get_file() > king_file);
         }
+        self.board.castle_rights(color.0).has_kingside()
+    }
 
-        // Parse the halfmove clock and fullmove number
-        let halfmove_clock = parts[4]
-            .parse::<u8>()
-            .map_err(|_| PyValueError::new_err("Invalid halfmove clock"))?;
-        let fullmove_number = parts[5]
-            .parse::<u8>()
-            .map_err(|_| PyValueError::new_err("Invalid fullmove number"))?;
-
-        // Parse the board using the chess crate
-        let board = chess::Board::from_str(fen)
-            .map_err(|e| PyValueError::new_err(format!("Invalid FEN: {e}")))?;
-
-        // We can assume the GIL is acquired, since this function is only called from Python
-        let py = unsafe { Python::assume_gil_acquired() };
+    /// Check if a color has queenside castling rights.
+    /// Doesn't check if castling is currently possible.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().has_queenside_castle_rights(rust_chess.WHITE)
+    /// True
+    /// >>> rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w Kkq - 0 1").has_queenside_castle_rights(rust_chess.WHITE)
+    /// False
+    /// ```
+    #[inline]
+    fn has_queenside_castle_rights(&self, color: PyColor) -> bool {
+        if self.chess960 {
+            let king_file = self.board.king_square(color.0).get_file();
+            return self.get_chess960_castling_rooks(color.0).any(|rook| rook.get_file() < king_file);
+        }
+        self.board.castle_rights(color.0).has_queenside()
+    }
 
-        // Create a new move generator using the chess crate
-        let move_gen = Py::new(py, PyMoveGenerator(chess::MoveGen::new_legal(&board)))?;
+    /// Get the castling rights of a color as in a FEN string ("KQ", "K", "Q", or "-" for white, lowercase for black).
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w Kkq - 0 1")
+    /// >>> board.castle_rights(rust_chess.WHITE)
+    /// 'K'
+    /// >>> board.castle_rights(rust_chess.BLACK)
+    /// 'kq'
+    /// >>> rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 0 1").castle_rights(rust_chess.WHITE)
+    /// '-'
+    /// ```
+    #[inline]
+    fn castle_rights(&self, color: PyColor) -> String {
+        let rights = match (self.has_kingside_castle_rights(color), self.has_queenside_castle_rights(color)) {
+            (true, true) => "KQ",
+            (true, false) => "K",
+            (false, true) => "Q",
+            (false, false) => "-",
+        };
 
-        Ok(PyBoard {
-            board,
-            move_gen,
-            halfmove_clock,
-            fullmove_number,
-        })
+        if color.0 == chess::Color::White {
+            rights.to_string()
+        } else {
+            rights.to_lowercase()
+        }
     }
 
-    /// Get the current player to move.
+    /// Get the castling rights ("K", "Q", "k", or "q") that this board has but another board doesn't.
+    /// Useful for debugging how castling rights change across moves.
     ///
     /// ```python
-    /// >>> board = rust_chess.Board()
-    /// >>> board.turn
-    /// True
-    /// >>> print(board.turn)
-    /// WHITE
+    /// >>> board = rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1")
+    /// >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("h1g1")))
+    /// ['K']
+    /// >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("a1a8")))
+    /// ['Q', 'q']
+    /// >>> board = rust_chess.Board("bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1", chess960=True)
+    /// >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("f1e1")))
+    /// ['Q']
+    /// >>> board = rust_chess.Board("bbqnnrkr/pppppppp/8/8/8/8/PPPPPP1P/BBQNNRKR w HFhf - 0 1", chess960=True)
+    /// >>> board.lost_castling_rights(board.make_move_new(rust_chess.Move("g1g2")))
+    /// ['K', 'Q']
     /// ```
-    #[getter]
     #[inline]
-    fn get_turn(&self) -> PyColor {
-        PyColor(self.board.side_to_move())
+    fn lost_castling_rights(&self, other: &PyBoard) -> Vec<&'static str> {
+        let mut lost = Vec::new();
+        for (color, kingside, queenside) in [(WHITE, "K", "Q"), (BLACK, "k", "q")] {
+            // Use the castling rights methods, since they also handle the Chess960 castling rooks
+            if self.has_kingside_castle_rights(color) && !other.has_kingside_castle_rights(color) {
+                lost.push(kingside);
+            }
+            if self.has_queenside_castle_rights(color) && !other.has_queenside_castle_rights(color) {
+                lost.push(queenside);
+            }
+        }
+        lost
     }
 
-    /// Get the en passant square, otherwise None.
+    /// Get the pieces as a (12, 8, 8) nested list of 0s and 1s, indexed as `planes[plane][rank][file]`.
+    /// The planes are white pawn, knight, bishop, rook, queen, king, then the same for black.
+    /// Ranks and files start from 0, so `planes[0][1][4]` is a white pawn on e2.
+    /// Can be converted with `numpy.array(board.to_planes())`.
     ///
     /// ```python
-    /// >>> rust_chess.Board().en_passant
+    /// >>> planes = rust_chess.Board().to_planes()
+    /// >>> planes[0][1]
+    /// [1, 1, 1, 1, 1, 1, 1, 1]
+    /// >>> sum(map(sum, planes[0] + planes[6]))
+    /// 16
+    /// >>> planes[11][7][4] # Black king on e8
+    /// 1
+    /// ```
+    #[inline]
+    fn to_planes(&self) -> Vec<Vec<Vec<u32>>> {
+        // Use u32 instead of u8, since PyO3 converts a Vec<u8> into bytes instead of a list
+        let mut planes = vec![vec![vec![0; 8]; 8]; 12];
+
+        for square in *self.board.combined() {
+            if let (Some(piece), Some(color)) = (self.board.piece_on(square), self.board.color_on(square)) {
+                let plane = color.to_index() * 6 + piece.to_index();
+                planes[plane][square.get_rank().to_index()][square.get_file().to_index()] = 1;
+            }
+        }
+
+        planes
+    }
+
+    /// Get the pieces as a list of 64 integers, indexed by square (a1 = 0, h8 = 63).
+    /// Empty squares are 0, white pieces are 1 to 6 (pawn, knight, bishop, rook, queen, king), and black pieces are -1 to -6.
     ///
-    /// >>> rust_chess.Board().en_passant == None
+    /// ```python
+    /// >>> array = rust_chess.Board().to_array()
+    /// >>> array[0] # White rook on a1
+    /// 4
+    /// >>> array[60] # Black king on e8
+    /// -6
+    /// >>> array[16:48] == [0] * 32
     /// True
-    /// >>> rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4").en_passant
-    /// f5
     /// ```
-    #[getter]
     #[inline]
-    fn get_en_passant(&self) -> Option<PySquare> {
-        self.board.en_passant().map(PySquare)
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)] // Piece indices are less than 6
+    fn to_array(&self) -> Vec<i8> {
+        chess::ALL_SQUARES
+            .iter()
+            .map(|&square| match (self.board.piece_on(square), self.board.color_on(square)) {
+                (Some(piece), Some(chess::Color::White)) => piece.to_index() as i8 + 1,
+                (Some(piece), Some(chess::Color::Black)) => -(piece.to_index() as i8 + 1),
+                _ => 0,
+            })
+            .collect()
     }
 
-    /// Get the piece type on a square, otherwise None.
-    /// Different than `get_piece_on` because it returns the piece type, which does not include color.
+    /// Get the input features for a neural network as a flat list of 19 * 8 * 8 = 1216 floats,
+    /// indexed as `tensor[channel * 64 + rank * 8 + file]` (reshape to (19, 8, 8)).
+    /// The channels are:
+    /// - 0-11: the pieces, in the same order as `to_planes`
+    /// - 12: all ones if white is to move, otherwise all zeros
+    /// - 13-16: all ones for each castling right (white kingside, white queenside, black kingside, black queenside)
+    /// - 17: a one on the en passant target square (e.g. e3), if there is one
+    /// - 18: the halfmove clock divided by 100 (capped at 1)
     ///
     /// ```python
-    /// >>> rust_chess.Board().get_piece_type_on(rust_chess.A1)
-    /// R
-    /// >>> rust_chess.Board().get_piece_type_on(rust_chess.E8)
-    /// K
+    /// >>> tensor = rust_chess.Board().to_input_tensor()
+    /// >>> len(tensor)
+    /// 1216
+    /// >>> all(value == 1.0 for value in tensor[12 * 64:17 * 64]) # Side to move and castling rights
+    /// True
+    /// >>> sum(tensor[17 * 64:])
+    /// 0.0
     /// ```
     #[inline]
-    fn get_piece_type_on(&self, square: PySquare) -> Option<PyPieceType> {
-        // Get the piece on the square using the chess crate
-        self.board.piece_on(square.0).map(PyPieceType)
+    fn to_input_tensor(&self) -> Vec<f32> {
+        let mut tensor = vec![0.0; 19 * 64];
+        let mut fill_channel = |channel: usize, value: f32| tensor[channel * 64..(channel + 1) * 64].fill(value);
+
+        if self.board.side_to_move() == chess::Color::White {
+            fill_channel(12, 1.0);
+        }
+        for (channel, has_right) in [
+            (13, self.has_kingside_castle_rights(WHITE)),
+            (14, self.has_queenside_castle_rights(WHITE)),
+            (15, self.has_kingside_castle_rights(BLACK)),
+            (16, self.has_queenside_castle_rights(BLACK)),
+        ] {
+            if has_right {
+                fill_channel(channel, 1.0);
+            }
+        }
+        fill_channel(18, (f32::from(self.halfmove_clock) / 100.0).min(1.0));
+
+        for square in *self.board.combined() {
+            if let (Some(piece), Some(color)) = (self.board.piece_on(square), self.board.color_on(square)) {
+                let channel = color.to_index() * 6 + piece.to_index();
+                tensor[channel * 64 + square.to_index()] = 1.0;
+            }
+        }
+
+        // The chess crate stores the square of the pawn that can be captured, so use the square behind it
+        if let Some(target) = self
+            .board
+            .en_passant()
+            .and_then(|square| en_passant_target(square, self.board.side_to_move()))
+        {
+            tensor[17 * 64 + target.to_index()] = 1.0;
+        }
+
+        tensor
     }
 
-    /// Get the color of the piece on a square, otherwise None.
+    /// Get a hashable tuple snapshot of the position:
+    /// (board FEN, True if white to move, castling rights FEN, en passant target square index or None).
+    /// Ignores the halfmove clock and fullmove number, so transpositions produce equal tuples.
     ///
     /// ```python
-    /// >>> rust_chess.Board().get_color_on(rust_chess.A1)
+    /// >>> rust_chess.Board().as_tuple()
+    /// ('rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR', True, 'KQkq', None)
+    /// >>> seen = {rust_chess.Board().as_tuple(): 0}
+    /// >>> rust_chess.Board().as_tuple() in seen
     /// True
-    /// >>> print(rust_chess.Board().get_color_on(rust_chess.A1))
-    /// WHITE
-    /// >>> rust_chess.Board().get_color_on(rust_chess.E8)
-    /// False
+    /// >>> rust_chess.Board("bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w Hh - 0 1", chess960=True).as_tuple()
+    /// ('bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR', True, 'Kk', None)
+    /// >>> rust_chess.Board("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3").as_tuple()[3] == rust_chess.E3
+    /// True
+    /// ```
     #[inline]
-    fn get_color_on(&self, square: PySquare) -> Option<PyColor> {
-        // Get the color of the piece on the square using the chess crate
-        self.board.color_on(square.0).map(PyColor)
+    fn as_tuple(&self) -> (String, bool, String, Option<u8>) {
+        // Use the FEN string of the board, since it also has the Chess960 castling rights
+        let base_fen = self.get_fen();
+
+        // 0: board, 1: player, 2: castling, 3: en passant, 4: halfmove clock, 5: fullmove number
+        let parts: Vec<&str> = base_fen.split_whitespace().collect();
+
+        (
+            parts[0].to_string(),
+            self.board.side_to_move() == chess::Color::White,
+            parts[2].to_string(),
+            self.board
+                .en_passant()
+                .and_then(|square| en_passant_target(square, self.board.side_to_move()))
+                .map(|square| square.to_int()),
+        )
     }
 
-    /// Get the piece on a square (color-inclusive), otherwise None.
-    /// Different than `get_piece_on` because it returns the piece, which includes color.
+    /// Get the material balance in centipawns from the side to move's perspective.
+    /// Positive if the side to move is ahead in material.
+    /// Uses the default piece values (pawn 100, knight 300, bishop 300, rook 500, queen 900).
     ///
     /// ```python
-    /// >>> rust_chess.Board().get_piece_on(rust_chess.A1)
-    /// R
-    /// >>> rust_chess.Board().get_piece_on(rust_chess.E8)
-    /// k
+    /// >>> rust_chess.Board().relative_material()
+    /// 0
+    /// >>> rust_chess.Board("rnbqkbnr/ppp1pppp/8/3P4/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2").relative_material()
+    /// -100
     /// ```
     #[inline]
-    fn get_piece_on(&self, square: PySquare) -> Option<PyPiece> {
-        self.get_color_on(square).and_then(|color| {
-            self.get_piece_type_on(square)
-                .map(|piece_type| PyPiece { piece_type, color })
-        })
+    fn relative_material(&self) -> i32 {
+        let side_to_move = self.board.color_combined(self.board.side_to_move());
+        let opponent = self.board.color_combined(!self.board.side_to_move());
+
+        chess::ALL_PIECES
+            .iter()
+            .map(|&piece| {
+                let pieces = self.board.pieces(piece);
+                let count_difference = (pieces & side_to_move).popcnt().cast_signed() - (pieces & opponent).popcnt().cast_signed();
+                PIECE_VALUES[piece.to_index()] * count_difference
+            })
+            .sum()
     }
 
-    /// Get the king square of a certain color
+    /// Get the material balance in centipawns from white's perspective (white material minus black material).
+    /// Uses the default piece values (pawn 100, knight 300, bishop 300, rook 500, queen 900, king 0)
+    /// unless they are replaced by the given values.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().material_balance()
+    /// 0
+    /// >>> board = rust_chess.Board("rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+    /// >>> board.material_balance()
+    /// 900
+    /// >>> board.material_balance({rust_chess.QUEEN: 950})
+    /// 950
+    /// ```
     #[inline]
-    fn get_king_square(&self, color: PyColor) -> PySquare {
-        PySquare(self.board.king_square(color.0))
+    #[pyo3(signature = (values = None))]
+    fn material_balance(&self, values: Option<HashMap<PyPieceType, i32>>) -> i32 {
+        let values = values.unwrap_or_default();
+        let white = self.board.color_combined(chess::Color::White);
+        let black = self.board.color_combined(chess::Color::Black);
+
+        chess::ALL_PIECES
+            .iter()
+            .map(|&piece| {
+                let value = values.get(&PyPieceType(piece)).copied().unwrap_or(PIECE_VALUES[piece.to_index()]);
+                let pieces = self.board.pieces(piece);
+                let count_difference = (pieces & white).popcnt().cast_signed() - (pieces & black).popcnt().cast_signed();
+                value * count_difference
+            })
+            .sum()
     }
 
-    /// Check if a move is a capture or a pawn move.
-    /// Doesn't check legality.
+    /// Get the Zobrist hash of the board's position.
+    /// Useful as a key for transposition tables.
+    /// The halfmove clock and fullmove number are not included.
     ///
+    /// ```python
+    /// >>> rust_chess.Board().get_hash() == rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9").get_hash()
+    /// True
+    /// >>> rust_chess.Board().get_hash() == rust_chess.Board().make_move_new(rust_chess.Move("e2e4")).get_hash()
+    /// False
+    /// ```
     #[inline]
-    fn is_zeroing(&self, chess_move: PyMove) -> bool {
-        self.get_piece_type_on(chess_move.get_source()) == Some(PAWN) // Pawn move
-        || self.get_piece_type_on(chess_move.get_dest()).is_some() // Capture (moving piece onto other piece)
+    fn get_hash(&self) -> u64 {
+        self.board.get_hash()
     }
 
-    /// Check if the move is legal (supposedly very slow according to the chess crate).
-    /// Use this function for moves not generated by the move generator.
-    /// `is_legal_quick` is faster for moves generated by the move generator.
+    /// Get the material key of the board.
+    /// The key only depends on the number of pieces of each color and type (not their placement),
+    /// so it can be used to index endgame tables.
+    /// Each piece count is packed into 4 bits (white pawns in the lowest bits, black kings in the highest),
+    /// so different material always has a different key.
     ///
     /// ```python
-    /// >>> move = rust_chess.Move("e2e4")
-    /// >>> rust_chess.Board().is_legal_move(move)
+    /// >>> kqkr1 = rust_chess.Board("3rk3/8/8/8/8/8/8/3QK3 w - - 0 1")
+    /// >>> kqkr2 = rust_chess.Board("4k3/8/2r5/8/8/5Q2/8/K7 b - - 0 1")
+    /// >>> kqkr1.material_key() == kqkr2.material_key()
     /// True
-    /// >>> move2 = rust_chess.Move("e2e5")
-    /// >>> rust_chess.Board().is_legal_move(move2)
+    /// >>> kqkr1.material_key() == rust_chess.Board("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").material_key()
     /// False
     /// ```
     #[inline]
-    fn is_legal_move(&self, chess_move: PyMove) -> bool {
-        // Check if the move is legal using the chess crate
-        chess::Board::legal(&self.board, chess_move.0)
+    fn material_key(&self) -> u64 {
+        let mut key: u64 = 0;
+        for (color_index, color) in chess::ALL_COLORS.iter().enumerate() {
+            for (piece_index, piece) in chess::ALL_PIECES.iter().enumerate() {
+                let count = (self.board.pieces(*piece) & self.board.color_combined(*color)).popcnt();
+                key |= u64::from(count) << (4 * (6 * color_index + piece_index));
+            }
+        }
+        key
     }
 
-    // TODO: is_legal_quick
+    /// Get the hash of the board's position using the Zobrist hash.
+    /// The halfmove clock and fullmove number are not included.
+    ///
+    /// ```python
+    /// >>> board1 = rust_chess.Board()
+    /// >>> board2 = rust_chess.Board()
+    /// >>> for uci in ["g1f3", "g8f6", "b1c3"]:
+    /// ...     board1.make_move(rust_chess.Move(uci))
+    /// >>> for uci in ["b1c3", "g8f6", "g1f3"]:
+    /// ...     board2.make_move(rust_chess.Move(uci))
+    /// >>> hash(board1) == hash(board2)
+    /// True
+    /// ```
+    #[inline]
+    fn __hash__(&self) -> u64 {
+        self.get_hash()
+    }
 
-    /// Make a null move onto a new board.
-    /// Returns None if the current player is in check.
+    /// Compare the board's position to another board.
+    /// Compares the piece placement, side to move, castling rights, and en passant square.
+    /// The halfmove clock and fullmove number are not compared.
     ///
+    /// ```python
+    /// >>> rust_chess.Board() == rust_chess.Board()
+    /// True
+    /// >>> rust_chess.Board() == rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9")
+    /// True
+    /// >>> rust_chess.Board() == rust_chess.Board().make_move_new(rust_chess.Move("e2e4"))
+    /// False
+    /// ```
     #[inline]
-    fn make_null_move_new(&self) -> PyResult<Option<Self>> {
-        // Get the new board using the chess crate
-        let Some(new_board) = self.board.null_move() else {
-            return Ok(None);
-        };
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        if let Ok(other_board) = other.extract::<PyRef<PyBoard>>() {
+            self.board == other_board.board && self.castling_rooks == other_board.castling_rooks
+        } else {
+            false
+        }
+    }
 
-        // Increment the halfmove clock
-        let halfmove_clock: u8 = self.halfmove_clock + 1;
+    /// Compare the board to another board.
+    /// Compares only the position by default (same as `==`), which is what counts for repetitions.
+    /// If `include_counters` is True, the halfmove clock and fullmove number must also be equal.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> other = rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9")
+    /// >>> board.equals(other)
+    /// True
+    /// >>> board.equals(other, include_counters=True)
+    /// False
+    /// >>> board.equals(rust_chess.Board(), include_counters=True)
+    /// True
+    /// ```
+    #[inline]
+    #[pyo3(signature = (other, include_counters = false))]
+    fn equals(&self, other: &PyBoard, include_counters: bool) -> bool {
+        let same_position = self.board == other.board && self.castling_rooks == other.castling_rooks;
+        if !include_counters {
+            return same_position;
+        }
 
-        // Increment fullmove number if black moves
-        let fullmove_number: u8 = if self.board.side_to_move() == chess::Color::Black {
-            self.fullmove_number + 1
-        } else {
-            self.fullmove_number
-        };
+        same_position && self.halfmove_clock == other.halfmove_clock && self.fullmove_number == other.fullmove_number
+    }
 
+    /// Create a copy of the board.
+    /// The copy is independent of the original and has a fresh move generator.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board_copy = board.copy()
+    /// >>> board_copy.make_move(rust_chess.Move("e2e4"))
+    /// >>> board
+    /// rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+    /// >>> moves = board.generate_legal_moves()
+    /// >>> _ = next(moves), next(moves)
+    /// >>> len(moves), len(board.copy().generate_legal_moves())
+    /// (18, 20)
+    /// ```
+    #[inline]
+    fn copy(&self) -> PyResult<Self> {
         // We can assume the GIL is acquired, since this function is only called from Python
         let py = unsafe { Python::assume_gil_acquired() };
 
         // Create a new move generator using the chess crate
-        let move_gen = Py::new(py, PyMoveGenerator(chess::MoveGen::new_legal(&new_board)))?;
+        let move_gen = Py::new(py, PyMoveGenerator::new(&self.board))?;
 
-        Ok(Some(PyBoard {
-            board: new_board,
+        Ok(PyBoard {
+            board: self.board,
             move_gen,
-            halfmove_clock,
-            fullmove_number,
-        }))
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+            chess960: self.chess960,
+            castling_rooks: self.castling_rooks,
+            move_stack: self.move_stack.clone(),
+            status: self.status.clone(),
+        })
     }
 
-    /// Make a move onto a new board
+    /// Create a copy of the board (used by `copy.copy`).
     ///
-    #[pyo3(signature = (chess_move, check_legality = false))]
-    fn make_move_new(&self, chess_move: PyMove, check_legality: bool) -> PyResult<Self> {
-        // If we are checking legality, check if the move is legal
-        if check_legality && !self.is_legal_move(chess_move) {
-            return Err(PyValueError::new_err("Illegal move"));
-        }
-
-        // Make the move onto a new board using the chess crate
-        let new_board: chess::Board = self.board.make_move_new(chess_move.0);
+    /// ```python
+    /// >>> import copy
+    /// >>> copy.copy(rust_chess.Board())
+    /// rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+    /// ```
+    #[inline]
+    fn __copy__(&self) -> PyResult<Self> {
+        self.copy()
+    }
+
+    /// Create a copy of the board (used by `copy.deepcopy`).
+    ///
+    /// ```python
+    /// >>> import copy
+    /// >>> copy.deepcopy(rust_chess.Board())
+    /// rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+    /// ```
+    #[inline]
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.copy()
+    }
+
+    /// Get the state of the board for pickling.
+    /// The state is the FEN string of the position before the first move on the move stack
+    /// (including the move counters), the moves on the move stack, and whether the board is in Chess960 mode.
+    ///
+    /// ```python
+    /// >>> import pickle
+    /// >>> pickle.loads(pickle.dumps(rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9")))
+    /// rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 5 9
+    /// ```
+    #[inline]
+    fn __getstate__(&self) -> (String, Vec<PyMove>, bool) {
+        let root_fen = self.get_root_fen();
+        let moves = self.move_stack.iter().map(|state| state.chess_move).collect();
+
+        (root_fen, moves, self.chess960)
+    }
+
+    /// Restore the state of the board when unpickling.
+    /// Replays the moves from the starting FEN string to rebuild the move stack.
+    ///
+    #[inline]
+    fn __setstate__(&mut self, state: (String, Vec<PyMove>, bool)) -> PyResult<()> {
+        let (root_fen, moves, chess960) = state;
+
+        // Parse the FEN string (also rebuilds the move generator)
+        *self = PyBoard::from_fen(&root_fen, chess960)?;
+
+        // Replay the moves
+        for chess_move in moves {
+            if chess_move == PyMove::default() {
+                self.make_null_move()?;
+            } else {
+                self.make_move(chess_move, false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Export the game played on the board as a PGN string.
+    /// Uses the seven tag roster ("?" for unknown values) and the result of the game ("*" if ongoing).
+    /// Given headers replace the defaults, and other headers are added after the roster in sorted order.
+    /// Adds the `SetUp` and `FEN` headers if the game didn't start from the starting position,
+    /// and the `Variant` header in Chess960 mode.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> for uci in ["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"]:
+    /// ...     board.push(rust_chess.Move(uci))
+    /// >>> print(board.to_pgn({"White": "Alice", "Black": "Bob"}))
+    /// [Event "?"]
+    /// [Site "?"]
+    /// [Date "????.??.??"]
+    /// [Round "?"]
+    /// [White "Alice"]
+    /// [Black "Bob"]
+    /// [Result "1-0"]
+    /// <BLANKLINE>
+    /// 1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0
+    /// ```
+    #[inline]
+    #[pyo3(signature = (headers = None))]
+    fn to_pgn(&self, headers: Option<HashMap<String, String>>) -> PyResult<String> {
+        let mut headers = headers.unwrap_or_default();
+        let root_fen = self.get_root_fen();
+        if self.chess960 {
+            headers.entry("Variant".to_string()).or_insert_with(|| "Chess960".to_string());
+        }
+        let result = headers
+            .remove("Result")
+            .unwrap_or_else(|| self.result(false).unwrap_or("*").to_string());
+
+        let mut pgn = String::new();
+
+        // Seven tag roster first, then any other headers
+        let roster = [
+            ("Event", "?"),
+            ("Site", "?"),
+            ("Date", "????.??.??"),
+            ("Round", "?"),
+            ("White", "?"),
+            ("Black", "?"),
+        ];
+        for (key, default) in roster {
+            let value = headers.remove(key).unwrap_or_else(|| default.to_string());
+            pgn.push_str(&format_pgn_header(key, &value));
+        }
+        pgn.push_str(&format_pgn_header("Result", &result));
+        if root_fen != STARTING_FEN && !headers.contains_key("FEN") {
+            pgn.push_str(&format_pgn_header("SetUp", &headers.remove("SetUp").unwrap_or_else(|| "1".to_string())));
+            pgn.push_str(&format_pgn_header("FEN", &root_fen));
+        }
+        let mut other_headers: Vec<(String, String)> = headers.into_iter().collect();
+        other_headers.sort();
+        for (key, value) in other_headers {
+            pgn.push_str(&format_pgn_header(&key, &value));
+        }
+        pgn.push('\n');
+
+        // Movetext, with the move number before each white move (and before the first move if black starts)
+        for (index, state) in self.move_stack.iter().enumerate() {
+            if state.board.side_to_move() == chess::Color::White {
+                let _ = write!(pgn, "{}. ", state.fullmove_number);
+            } else if index == 0 {
+                let _ = write!(pgn, "{}... ", state.fullmove_number);
+            }
+
+            // Null moves can't be written in SAN, so use the common "--" extension
+            if state.chess_move == PyMove::default() {
+                pgn.push_str("--");
+            } else {
+                pgn.push_str(&format_san(&state.board, state.chess_move.0, true)?);
+            }
+            pgn.push(' ');
+        }
+        pgn.push_str(&result);
+
+        Ok(pgn)
+    }
+
+    /// Create a new board by replaying the moves of a PGN string.
+    /// Starts from the `FEN` header if there is one, otherwise the starting position.
+    /// The board is in Chess960 mode if the `Variant` header is "Chess960" (or "Fischerandom").
+    /// Comments, NAGs (e.g. "$1"), and variations are ignored, and only the first game is read.
+    /// Raises a `ValueError` at the first invalid or illegal move.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board.from_pgn('''
+    /// ... [Event "?"]
+    /// ... [Result "1-0"]
+    /// ...
+    /// ... 1. e4 e5 2. Bc4 {Aiming at f7} Nc6 3. Qh5 Nf6?? (3... g6 4. Qf3) 4. Qxf7# 1-0
+    /// ... ''')
+    /// >>> board
+    /// r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4
+    /// >>> board.peek()
+    /// Move(h5, f7, None)
+    /// >>> rust_chess.Board.from_pgn("1. e4 e5 2. Ke3")
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid or illegal move in PGN at 2. Ke3
+    /// ```
+    #[staticmethod]
+    fn from_pgn(pgn: &str) -> PyResult<Self> {
+        // Split the headers from the movetext
+        let mut fen = None;
+        let mut chess960 = false;
+        let mut movetext = String::new();
+        for line in pgn.lines() {
+            let line = line.trim();
+            if movetext.trim().is_empty() && line.starts_with('[') {
+                let tag = line.trim_start_matches('[').trim_end_matches(']');
+                if let Some((key, value)) = tag.split_once(' ') {
+                    let value = value.trim().trim_matches('"');
+                    if key == "FEN" {
+                        fen = Some(value.to_string());
+                    } else if key == "Variant" {
+                        let variant = value.to_lowercase();
+                        chess960 = variant.contains("960") || variant == "fischerandom";
+                    }
+                }
+            } else if !movetext.trim().is_empty() && line.starts_with('[') {
+                // The headers of the next game
+                break;
+            } else {
+                movetext.push_str(line);
+                movetext.push('\n');
+            }
+        }
+
+        let mut board = PyBoard::from_fen(fen.as_deref().unwrap_or(STARTING_FEN), chess960)?;
+
+        for token in pgn_movetext_tokens(&movetext) {
+            // Skip move numbers (e.g. "1." or "1..."), which may be attached to the move (e.g. "1.e4")
+            let token = match token.trim_start_matches(|c: char| c.is_ascii_digit()).strip_prefix('.') {
+                Some(rest) => rest.trim_start_matches('.'),
+                None => token.as_str(),
+            };
+
+            match token {
+                "" => {}
+                "1-0" | "0-1" | "1/2-1/2" | "*" => break,
+                _ if token.starts_with('$') => {} // NAG
+                "--" => {
+                    if !board.make_null_move()? {
+                        return Err(PyValueError::new_err(format!(
+                            "Illegal null move in PGN at {}",
+                            board.format_move_number(token)
+                        )));
+                    }
+                }
+                _ => {
+                    let chess_move = board.parse_san(token).map_err(|_| {
+                        PyValueError::new_err(format!(
+                            "Invalid or illegal move in PGN at {}",
+                            board.format_move_number(token)
+                        ))
+                    })?;
+                    board.make_move(chess_move, false)?;
+                }
+            }
+        }
+
+        Ok(board)
+    }
+
+    /// Create a new board from an EPD string, and get its operations (e.g. "bm", "id") as a dictionary.
+    /// The position only has the first four FEN fields, so the move counters are taken from the
+    /// "hmvc" and "fmvn" operations if they exist (otherwise 0 and 1).
+    /// Quotes around operands are removed, and operations without operands have empty strings.
+    ///
+    /// ```python
+    /// >>> board, operations = rust_chess.Board.from_epd('2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - bm Qg6; id "WAC.001";')
+    /// >>> board
+    /// 2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - 0 1
+    /// >>> operations["id"]
+    /// 'WAC.001'
+    /// >>> operations["bm"]
+    /// 'Qg6'
+    /// ```
+    #[staticmethod]
+    fn from_epd(epd: &str) -> PyResult<(PyBoard, HashMap<String, String>)> {
+        let fields: Vec<&str> = epd.split_whitespace().take(4).collect();
+        if fields.len() != 4 {
+            return Err(PyValueError::new_err("EPD string must have at least 4 parts"));
+        }
+
+        // Everything after the four position fields is operations
+        let mut operations_str = epd.trim_start();
+        for _ in 0..4 {
+            operations_str = operations_str
+                .trim_start_matches(|c: char| !c.is_whitespace())
+                .trim_start();
+        }
+        let operations = parse_epd_operations(operations_str);
+
+        let halfmove_clock = operations.get("hmvc").map_or("0", String::as_str);
+        let fullmove_number = operations.get("fmvn").map_or("1", String::as_str);
+        let board = PyBoard::from_fen(&format!("{} {halfmove_clock} {fullmove_number}", fields.join(" ")), false)?;
+
+        Ok((board, operations))
+    }
+
+    /// Get the EPD string of the board: the first four FEN fields, then any operations sorted by opcode.
+    /// Operands of the "id", "c0"-"c9", and "v0"-"v9" opcodes are quoted.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - 0 1")
+    /// >>> epd = board.to_epd({"id": "WAC.001", "bm": "Qg6"})
+    /// >>> epd
+    /// '2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - bm Qg6; id "WAC.001";'
+    /// >>> rust_chess.Board.from_epd(epd) == (board, {"id": "WAC.001", "bm": "Qg6"})
+    /// True
+    /// ```
+    #[inline]
+    #[pyo3(signature = (operations = None))]
+    fn to_epd(&self, operations: Option<HashMap<String, String>>) -> String {
+        let fen = self.get_fen();
+        let mut epd = fen.split_whitespace().take(4).collect::<Vec<&str>>().join(" ");
+
+        let mut operations: Vec<(String, String)> = operations.unwrap_or_default().into_iter().collect();
+        operations.sort();
+        for (opcode, operands) in operations {
+            let is_string = opcode == "id"
+                || (opcode.len() == 2 && (opcode.starts_with('c') || opcode.starts_with('v')) && opcode.ends_with(|c: char| c.is_ascii_digit()));
+            if operands.is_empty() {
+                let _ = write!(epd, " {opcode};");
+            } else if is_string {
+                let _ = write!(epd, " {opcode} \"{operands}\";");
+            } else {
+                let _ = write!(epd, " {opcode} {operands};");
+            }
+        }
+
+        epd
+    }
+
+    /// Check if a FEN string is a valid position, raising a `ValueError` naming the problem if not.
+    /// Checks the format of each field, that each color has exactly one king, that no pawns are on the first or last rank,
+    /// that the castling rights match the kings and rooks, that the en passant square matches a pawn that just moved two squares,
+    /// and that the side not to move is not in check.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+    /// >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKKBNR w KQkq - 0 1")
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid FEN: white must have exactly one king (found 2)
+    /// >>> rust_chess.Board.validate_fen("rnbqkbnP/pppppppp/8/8/8/8/PPPPPPP1/RNBQKBNR w KQq - 0 1")
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid FEN: pawn on h8 (pawns can't be on the first or last rank)
+    /// >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQxq - 0 1")
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid FEN: invalid castling rights 'KQxq'
+    /// >>> rust_chess.Board.validate_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1")
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid FEN: invalid side to move 'x' (must be 'w' or 'b')
+    /// ```
+    #[staticmethod]
+    fn validate_fen(fen: &str) -> PyResult<()> {
+        let invalid = |message: String| Err(PyValueError::new_err(format!("Invalid FEN: {message}")));
+
+        let parts: Vec<&str> = fen.split_whitespace().collect();
+        if parts.len() != 6 {
+            return invalid(format!("must have exactly 6 parts (found {})", parts.len()));
+        }
+
+        // Piece placement, indexed by rank then file (starting from a1)
+        let mut pieces = [[None::<char>; 8]; 8];
+        let ranks: Vec<&str> = parts[0].split('/').collect();
+        if ranks.len() != 8 {
+            return invalid(format!("piece placement must have 8 ranks (found {})", ranks.len()));
+        }
+        for (rank_index, rank_str) in ranks.iter().rev().enumerate() {
+            let mut file_index: u32 = 0;
+            for c in rank_str.chars() {
+                if let Some(empty_squares) = c.to_digit(10).filter(|n| (1..=8).contains(n)) {
+                    file_index += empty_squares;
+                } else if "PNBRQKpnbrqk".contains(c) {
+                    if let Some(square) = pieces[rank_index].get_mut(file_index as usize) {
+                        *square = Some(c);
+                    }
+                    file_index += 1;
+                } else {
+                    return invalid(format!("invalid piece placement character '{c}'"));
+                }
+            }
+            if file_index != 8 {
+                return invalid(format!("rank {} must have 8 squares (found {file_index})", rank_index + 1));
+            }
+        }
+        let square_name = |rank_index: usize, file_index: usize| {
+            chess::Square::make_square(chess::Rank::from_index(rank_index), chess::File::from_index(file_index)).to_string()
+        };
+
+        // Kings
+        for (king, color) in [('K', "white"), ('k', "black")] {
+            let count = pieces.iter().flatten().filter(|&&piece| piece == Some(king)).count();
+            if count != 1 {
+                return invalid(format!("{color} must have exactly one king (found {count})"));
+            }
+        }
+
+        // Pawns on the first or last rank
+        for rank_index in [0, 7] {
+            if let Some(file_index) = pieces[rank_index].iter().position(|&piece| matches!(piece, Some('P' | 'p'))) {
+                return invalid(format!(
+                    "pawn on {} (pawns can't be on the first or last rank)",
+                    square_name(rank_index, file_index)
+                ));
+            }
+        }
+
+        // Side to move
+        let side_to_move = parts[1].to_lowercase();
+        if side_to_move != "w" && side_to_move != "b" {
+            return invalid(format!("invalid side to move '{}' (must be 'w' or 'b')", parts[1]));
+        }
+
+        // Castling rights: "-" or some of "KQkq" without repeats, with the king and rook on their starting squares
+        let castling = parts[2];
+        if castling != "-" {
+            let has_repeats = castling.chars().enumerate().any(|(i, c)| castling[..i].contains(c));
+            if castling.is_empty() || has_repeats || !castling.chars().all(|c| "KQkq".contains(c)) {
+                return invalid(format!("invalid castling rights '{castling}'"));
+            }
+            for c in castling.chars() {
+                let (rank_index, king, rook, rook_file_index) = match c {
+                    'K' => (0, 'K', 'R', 7),
+                    'Q' => (0, 'K', 'R', 0),
+                    'k' => (7, 'k', 'r', 7),
+                    _ => (7, 'k', 'r', 0),
+                };
+                if pieces[rank_index][4] != Some(king) || pieces[rank_index][rook_file_index] != Some(rook) {
+                    return invalid(format!(
+                        "castling rights '{c}' need the king on {} and a rook on {}",
+                        square_name(rank_index, 4),
+                        square_name(rank_index, rook_file_index)
+                    ));
+                }
+            }
+        }
+
+        // En passant square: behind a pawn that just moved two squares
+        let en_passant = parts[3];
+        if en_passant != "-" {
+            let (target_rank_index, pawn_rank_index, pawn) = if side_to_move == "w" { (5, 4, 'p') } else { (2, 3, 'P') };
+            let valid = chess::Square::from_str(en_passant).is_ok_and(|square| {
+                let file_index = square.get_file().to_index();
+                square.get_rank().to_index() == target_rank_index
+                    && pieces[target_rank_index][file_index].is_none()
+                    && pieces[pawn_rank_index][file_index] == Some(pawn)
+            });
+            if !valid {
+                return invalid(format!("invalid en passant square '{en_passant}'"));
+            }
+        }
+
+        // Move counters
+        if parts[4].parse::<u8>().is_err() {
+            return invalid(format!("invalid halfmove clock '{}'", parts[4]));
+        }
+        if parts[5].parse::<u16>().is_err() {
+            return invalid(format!("invalid fullmove number '{}'", parts[5]));
+        }
+
+        // The remaining invalid positions have the side not to move in check (including touching kings)
+        let fen = format!("{} {side_to_move} {}", parts[0], parts[2..].join(" "));
+        if chess::Board::from_str(&fen).is_err() {
+            return invalid("the side not to move is in check".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Create a new board from a FEN string.
+    /// Raises a `ValueError` naming the problem if the FEN string is invalid (see `validate_fen`).
+    /// The side to move is case-insensitive ("W" and "B" are accepted).
+    ///
+    /// If `chess960` is True, the board is in Chess960 mode, and the castling rights can be in
+    /// X-FEN (`KQkq` for the outermost rooks, otherwise the file of the rook) or Shredder-FEN (e.g. `HAha`).
+    /// The castling rights are kept track of and written as X-FEN, but castling moves are not generated,
+    /// since the chess crate only supports standard castling.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board.from_fen("rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2")
+    /// rnbqkbnr/ppp1pppp/8/3p4/2P1P3/8/PP1P1PPP/RNBQKBNR b KQkq - 0 2
+    /// >>> rust_chess.Board.from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR W KQkq - 0 1").turn
+    /// True
+    /// >>> board = rust_chess.Board.from_fen("bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1", chess960=True)
+    /// >>> board
+    /// bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w KQkq - 0 1
+    /// >>> len(board.get_legal_moves())
+    /// 20
+    /// ```
+    #[staticmethod]
+    #[pyo3(signature = (fen, chess960 = false))]
+    fn from_fen(fen: &str, chess960: bool) -> PyResult<Self> {
+        let (board, halfmove_clock, fullmove_number, castling_rooks) = parse_fen(fen, chess960)?;
+
+        let mut new_board = PyBoard::from_board(board, halfmove_clock, fullmove_number)?;
+        new_board.chess960 = chess960;
+        new_board.castling_rooks = castling_rooks;
+
+        Ok(new_board)
+    }
+
+    /// Set the board to the position of a FEN string, clearing the move stack.
+    /// In Chess960 mode, the FEN string is parsed as a Chess960 position.
+    /// Reuses the existing move generator instead of creating a new one.
+    /// Raises a `ValueError` if the FEN string is invalid, leaving the board unchanged.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.set_fen("4k3/8/8/8/8/8/8/4K2R w K - 3 40")
+    /// >>> board.get_fen()
+    /// '4k3/8/8/8/8/8/8/4K2R w K - 3 40'
+    /// >>> len(list(board.generate_legal_moves()))
+    /// 15
+    /// ```
+    #[inline]
+    fn set_fen(&mut self, fen: &str) -> PyResult<()> {
+        let (board, halfmove_clock, fullmove_number, castling_rooks) = parse_fen(fen, self.chess960)?;
+        self.set_board(board, halfmove_clock, fullmove_number)?;
+        self.castling_rooks = castling_rooks;
+
+        Ok(())
+    }
+
+    /// Reset the board to the starting position, clearing the move stack.
+    /// Reuses the existing move generator instead of creating a new one.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.push(rust_chess.Move("e2e4"))
+    /// >>> board.push(rust_chess.Move("e7e5"))
+    /// >>> board.reset()
+    /// >>> board
+    /// rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+    /// >>> len(list(board.generate_legal_moves()))
+    /// 20
+    /// ```
+    #[inline]
+    fn reset(&mut self) -> PyResult<()> {
+        self.set_fen(STARTING_FEN)
+    }
+
+    /// Get the current player to move.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.turn
+    /// True
+    /// >>> print(board.turn)
+    /// WHITE
+    /// ```
+    #[getter]
+    #[inline]
+    fn get_turn(&self) -> PyColor {
+        PyColor(self.board.side_to_move())
+    }
+
+    /// Get the en passant square, otherwise None.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().en_passant
+    ///
+    /// >>> rust_chess.Board().en_passant == None
+    /// True
+    /// >>> rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4").en_passant
+    /// f5
+    /// ```
+    #[getter]
+    #[inline]
+    fn get_en_passant(&self) -> Option<PySquare> {
+        self.board.en_passant().map(PySquare)
+    }
+
+    /// Get the piece type on a square, otherwise None.
+    /// Different than `get_piece_on` because it returns the piece type, which does not include color.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().get_piece_type_on(rust_chess.A1)
+    /// R
+    /// >>> rust_chess.Board().get_piece_type_on(rust_chess.E8)
+    /// K
+    /// ```
+    #[inline]
+    fn get_piece_type_on(&self, square: PySquare) -> Option<PyPieceType> {
+        // Get the piece on the square using the chess crate
+        self.board.piece_on(square.0).map(PyPieceType)
+    }
+
+    /// Get the color of the piece on a square, otherwise None.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().get_color_on(rust_chess.A1)
+    /// True
+    /// >>> print(rust_chess.Board().get_color_on(rust_chess.A1))
+    /// WHITE
+    /// >>> rust_chess.Board().get_color_on(rust_chess.E8)
+    /// False
+    #[inline]
+    fn get_color_on(&self, square: PySquare) -> Option<PyColor> {
+        // Get the color of the piece on the square using the chess crate
+        self.board.color_on(square.0).map(PyColor)
+    }
+
+    /// Get the piece on a square (color-inclusive), otherwise None.
+    /// Different than `get_piece_on` because it returns the piece, which includes color.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().get_piece_on(rust_chess.A1)
+    /// R
+    /// >>> rust_chess.Board().get_piece_on(rust_chess.E8)
+    /// k
+    /// ```
+    #[inline]
+    fn get_piece_on(&self, square: PySquare) -> Option<PyPiece> {
+        self.get_color_on(square).and_then(|color| {
+            self.get_piece_type_on(square)
+                .map(|piece_type| PyPiece { piece_type, color })
+        })
+    }
+
+    /// Get a dictionary of every occupied square to the piece on it, ordered by square.
+    ///
+    /// ```python
+    /// >>> piece_map = rust_chess.Board().piece_map()
+    /// >>> len(piece_map)
+    /// 32
+    /// >>> piece_map[rust_chess.A1]
+    /// R
+    /// >>> rust_chess.E4 in piece_map
+    /// False
+    /// ```
+    #[inline]
+    fn piece_map(&self) -> BTreeMap<PySquare, PyPiece> {
+        self.board
+            .combined()
+            .map(|square| {
+                let square = PySquare(square);
+                // The square is occupied, so there is always a piece on it
+                (square, self.get_piece_on(square).unwrap())
+            })
+            .collect()
+    }
+
+    /// Get a list of every occupied square with its piece type and color, ordered by square.
+    ///
+    /// ```python
+    /// >>> pieces = rust_chess.Board().enumerate_pieces()
+    /// >>> len(pieces)
+    /// 32
+    /// >>> pieces[0]
+    /// (a1, R, True)
+    /// >>> pieces[0] == (rust_chess.A1, rust_chess.ROOK, rust_chess.WHITE)
+    /// True
+    /// ```
+    #[inline]
+    fn enumerate_pieces(&self) -> Vec<(PySquare, PyPieceType, PyColor)> {
+        self.board
+            .combined()
+            .map(|square| {
+                // The square is occupied, so there is always a piece and color on it
+                (
+                    PySquare(square),
+                    PyPieceType(self.board.piece_on(square).unwrap()),
+                    PyColor(self.board.color_on(square).unwrap()),
+                )
+            })
+            .collect()
+    }
+
+    /// Get the king square of a certain color
+    #[inline]
+    fn get_king_square(&self, color: PyColor) -> PySquare {
+        PySquare(self.board.king_square(color.0))
+    }
+
+    /// Check if a move is a capture or a pawn move.
+    /// Doesn't check legality.
+    ///
+    #[inline]
+    fn is_zeroing(&self, chess_move: PyMove) -> bool {
+        self.get_piece_type_on(chess_move.get_source()) == Some(PAWN) // Pawn move
+        || self.get_piece_type_on(chess_move.get_dest()).is_some() // Capture (moving piece onto other piece)
+    }
+
+    /// Get the square of the piece a move would capture, otherwise None.
+    /// For en passant, this is the square of the captured pawn (not the destination square).
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4")
+    /// >>> board.captured_square(rust_chess.Move("e5f6"))
+    /// f5
+    /// >>> board.captured_square(rust_chess.Move("e5e6"))
+    ///
+    /// ```
+    #[inline]
+    fn captured_square(&self, chess_move: PyMove) -> Option<PySquare> {
+        let source = chess_move.0.get_source();
+        let dest = chess_move.0.get_dest();
+
+        // Normal capture: a piece on the destination square
+        if self.board.piece_on(dest).is_some() {
+            return Some(PySquare(dest));
+        }
+
+        // En passant: a pawn moving diagonally onto an empty square captures the pawn beside it
+        if self.board.piece_on(source) == Some(chess::Piece::Pawn) && source.get_file() != dest.get_file() {
+            return Some(PySquare(chess::Square::make_square(
+                source.get_rank(),
+                dest.get_file(),
+            )));
+        }
+
+        None
+    }
+
+    /// Get the piece a move would capture (color-inclusive), otherwise None.
+    /// Handles en passant, where the captured pawn is not on the destination square.
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4")
+    /// >>> board.captured_piece(rust_chess.Move("e5f6"))
+    /// p
+    /// >>> rust_chess.Board("r3k3/8/8/8/8/8/8/R3K3 w - - 0 1").captured_piece(rust_chess.Move("a1a8"))
+    /// r
+    /// ```
+    #[inline]
+    fn captured_piece(&self, chess_move: PyMove) -> Option<PyPiece> {
+        self.captured_square(chess_move)
+            .and_then(|square| self.get_piece_on(square))
+    }
+
+    /// Check if a move is a capture (including en passant).
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+    /// >>> board.is_capture(rust_chess.Move("e4d5"))
+    /// True
+    /// >>> board.is_capture(rust_chess.Move("e4e5"))
+    /// False
+    /// ```
+    #[inline]
+    fn is_capture(&self, chess_move: PyMove) -> bool {
+        self.board.piece_on(chess_move.0.get_dest()).is_some() || self.is_en_passant(chess_move)
+    }
+
+    /// Check if a move is an en passant capture, using the en passant square of the board.
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("rnbqkbnr/pp2p1pp/2p5/3pPp2/5P2/8/PPPP2PP/RNBQKBNR w KQkq f6 0 4")
+    /// >>> board.is_en_passant(rust_chess.Move("e5f6"))
+    /// True
+    /// >>> board.is_en_passant(rust_chess.Move("e5d6"))
+    /// False
+    /// ```
+    #[inline]
+    fn is_en_passant(&self, chess_move: PyMove) -> bool {
+        let source = chess_move.0.get_source();
+        let dest = chess_move.0.get_dest();
+
+        // The chess crate stores the square of the pawn that can be captured, which is beside the moving pawn
+        self.board.piece_on(source) == Some(chess::Piece::Pawn)
+            && source.get_file() != dest.get_file()
+            && self.board.en_passant() == Some(chess::Square::make_square(source.get_rank(), dest.get_file()))
+    }
+
+    /// Check if a move is castling (the king moving two squares).
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1")
+    /// >>> board.is_castling(rust_chess.Move("e1g1"))
+    /// True
+    /// >>> board.is_castling(rust_chess.Move("e1c1"))
+    /// True
+    /// >>> board.is_castling(rust_chess.Move("e1f1"))
+    /// False
+    /// ```
+    #[inline]
+    fn is_castling(&self, chess_move: PyMove) -> bool {
+        let source = chess_move.0.get_source();
+        let dest = chess_move.0.get_dest();
+
+        self.board.piece_on(source) == Some(chess::Piece::King)
+            && source.get_rank() == dest.get_rank()
+            && source.get_file().to_index().abs_diff(dest.get_file().to_index()) == 2
+    }
+
+    /// Check if a move gives check (including discovered checks).
+    /// Doesn't check legality, but moves without a piece of the side to move on the source square don't give check.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4k3/8/8/8/8/8/3B4/3RK3 w - - 0 1")
+    /// >>> board.gives_check(rust_chess.Move("d1d8")) # Direct check
+    /// True
+    /// >>> board.gives_check(rust_chess.Move("e1f2")) # Quiet move
+    /// False
+    /// >>> board = rust_chess.Board("4k3/8/8/8/8/8/4B3/4R1K1 w - - 0 1")
+    /// >>> board.gives_check(rust_chess.Move("e2d3")) # Discovered check
+    /// True
+    /// >>> board.gives_check(rust_chess.Move("e4e5")) # No piece on the source square
+    /// False
+    /// ```
+    #[inline]
+    fn gives_check(&self, chess_move: PyMove) -> bool {
+        self.try_make_move_new(chess_move)
+            .is_some_and(|new_board| *new_board.checkers() != chess::EMPTY)
+    }
+
+    /// Check if a move gives checkmate.
+    /// Doesn't check legality, but moves without a piece of the side to move on the source square don't give checkmate.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").gives_checkmate(rust_chess.Move("a1a8")) # Back-rank mate
+    /// True
+    /// >>> rust_chess.Board("6k1/5pp1/8/8/8/8/8/R5K1 w - - 0 1").gives_checkmate(rust_chess.Move("a1a8")) # The king escapes to h7
+    /// False
+    /// >>> rust_chess.Board().gives_checkmate(rust_chess.Move("e4e5")) # No piece on the source square
+    /// False
+    /// ```
+    #[inline]
+    fn gives_checkmate(&self, chess_move: PyMove) -> bool {
+        self.try_make_move_new(chess_move)
+            .is_some_and(|new_board| new_board.status() == chess::BoardStatus::Checkmate)
+    }
+
+    /// Get the Standard Algebraic Notation (SAN) of a move (e.g. "Nf3", "exd5", "O-O", "e8=Q#").
+    /// If `check_suffix` is False, the "+" and "#" suffixes for check and checkmate are left out.
+    /// Raises a `ValueError` if there is no piece on the source square.
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("rnbqkbnr/pppp1ppp/8/4p3/5P2/8/PPPPP1PP/RNBQKBNR w KQkq - 0 2")
+    /// >>> board.san(rust_chess.Move("f4e5"))
+    /// 'fxe5'
+    /// >>> board = rust_chess.Board("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+    /// >>> board.san(rust_chess.Move("d1h5"))
+    /// 'Qh5'
+    /// >>> board = rust_chess.Board("r1bqkbnr/pppp1ppp/2n5/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 2 3")
+    /// >>> board.san(rust_chess.Move("h5f7"))
+    /// 'Qxf7#'
+    /// >>> board.san(rust_chess.Move("h5f7"), check_suffix=False)
+    /// 'Qxf7'
+    /// ```
+    #[inline]
+    #[pyo3(signature = (chess_move, check_suffix = true))]
+    fn san(&self, chess_move: PyMove, check_suffix: bool) -> PyResult<String> {
+        format_san(&self.board, chess_move.0, check_suffix)
+    }
+
+    /// Get the MVV-LVA (most valuable victim, least valuable attacker) score of a move for ordering captures.
+    /// Captures score ten times the victim's value minus the attacker's index (0 for pawns to 5 for kings),
+    /// so any capture of a more valuable piece scores higher. Non-captures score 0.
+    /// Handles en passant. Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4k3/8/8/3q4/4P3/8/3p4/3QK3 w - - 0 1")
+    /// >>> board.mvv_lva_score(rust_chess.Move("e4d5")) # PxQ
+    /// 9000
+    /// >>> board.mvv_lva_score(rust_chess.Move("d1d2")) # QxP
+    /// 996
+    /// >>> board.mvv_lva_score(rust_chess.Move("e4e5")) # Quiet move
+    /// 0
+    /// ```
+    #[inline]
+    fn mvv_lva_score(&self, chess_move: PyMove) -> i32 {
+        let Some(victim) = self.get_captured_piece_type(chess_move) else {
+            return 0;
+        };
+
+        let attacker_index = self
+            .board
+            .piece_on(chess_move.0.get_source())
+            .map_or(0, |piece| piece.to_index());
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let attacker_index = attacker_index as i32;
+
+        PIECE_VALUES[victim.to_index()] * 10 - attacker_index
+    }
+
+    /// Get the static exchange evaluation (SEE) of a move: the material gained in centipawns if both sides
+    /// keep recapturing on the destination square with their least valuable piece, and can stop when it's better to.
+    /// Includes x-ray attackers behind other attackers, but ignores pins. Non-captures score 0.
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4k3/8/8/3p4/8/8/8/3RK3 w - - 0 1")
+    /// >>> board.see(rust_chess.Move("d1d5")) # Undefended pawn
+    /// 100
+    /// >>> board = rust_chess.Board("4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1")
+    /// >>> board.see(rust_chess.Move("d1d5")) # Pawn defended by a pawn
+    /// -800
+    /// >>> board = rust_chess.Board("3rk3/8/8/3p4/8/8/3R4/3RK3 w - - 0 1")
+    /// >>> board.see(rust_chess.Move("d2d5")) # Rook exchange after winning a pawn
+    /// 100
+    /// ```
+    #[inline]
+    fn see(&self, chess_move: PyMove) -> i32 {
+        let Some(captured_square) = self.captured_square(chess_move) else {
+            return 0;
+        };
+        let (Some(victim), Some(mut attacker)) = (
+            self.board.piece_on(captured_square.0),
+            self.board.piece_on(chess_move.0.get_source()),
+        ) else {
+            return 0;
+        };
+        let dest = chess_move.0.get_dest();
+
+        // Material gained after each capture, from the view of the side making it
+        let mut gains = vec![PIECE_VALUES[victim.to_index()]];
+        if let Some(promotion) = chess_move.0.get_promotion() {
+            gains[0] += PIECE_VALUES[promotion.to_index()] - PIECE_VALUES[chess::Piece::Pawn.to_index()];
+            attacker = promotion;
+        }
+
+        let mut occupied = *self.board.combined()
+            ^ chess::BitBoard::from_square(chess_move.0.get_source())
+            ^ chess::BitBoard::from_square(captured_square.0)
+            | chess::BitBoard::from_square(dest);
+        let mut color = !self.board.side_to_move();
+
+        loop {
+            let attackers = self.get_attackers(color, dest, occupied);
+
+            // Recapture with the least valuable attacker
+            let Some((piece, square)) = chess::ALL_PIECES.iter().find_map(|&piece| {
+                let pieces = attackers & self.board.pieces(piece);
+                (pieces != chess::EMPTY).then(|| (piece, pieces.to_square()))
+            }) else {
+                break;
+            };
+
+            // The king can't recapture onto a defended square
+            if piece == chess::Piece::King && self.get_attackers(!color, dest, occupied ^ chess::BitBoard::from_square(square)) != chess::EMPTY {
+                break;
+            }
+
+            gains.push(PIECE_VALUES[attacker.to_index()] - gains[gains.len() - 1]);
+            attacker = piece;
+            occupied ^= chess::BitBoard::from_square(square);
+            color = !color;
+        }
+
+        // Each side only recaptures if it gains from it
+        while gains.len() > 1 {
+            let gain = gains.pop().unwrap_or_default();
+            let previous = gains.len() - 1;
+            gains[previous] = -(-gains[previous]).max(gain);
+        }
+
+        gains[0]
+    }
+
+    /// Get an integer sort key for ordering moves (higher is more promising).
+    /// Combines the capture value (MVV-LVA), a promotion bonus, and a check bonus.
+    /// Captures rank above non-capturing moves, except for captures that lose material (negative `see`),
+    /// which rank below them.
+    /// Doesn't check legality, but moves without a piece of the side to move on the source square score 0.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4k3/8/8/3q4/4P3/8/8/4K2R w K - 0 1")
+    /// >>> board.move_sort_key(rust_chess.Move("e4d5")) # PxQ
+    /// 9000
+    /// >>> board.move_sort_key(rust_chess.Move("h1h8")) # Quiet check
+    /// 500
+    /// >>> board.move_sort_key(rust_chess.Move("h1h2")) # Quiet move
+    /// 0
+    /// >>> board = rust_chess.Board("4k3/8/4p3/3p4/q7/1P6/8/3QK3 w - - 0 1")
+    /// >>> queen_capture, quiet, losing_capture = rust_chess.Move("b3a4"), rust_chess.Move("e1f2"), rust_chess.Move("d1d5")
+    /// >>> board.move_sort_key(queen_capture) > board.move_sort_key(quiet) > board.move_sort_key(losing_capture)
+    /// True
+    /// >>> moves = sorted(board.generate_legal_moves(), key=board.move_sort_key, reverse=True)
+    /// >>> moves[0], moves[-1]
+    /// (Move(b3, a4, None), Move(d1, d5, None))
+    /// >>> board.move_sort_key(rust_chess.Move("e4e5")) # No piece on the source square
+    /// 0
+    /// ```
+    #[inline]
+    fn move_sort_key(&self, chess_move: PyMove) -> i32 {
+        // The chess crate can't make moves without a piece of the side to move on the source square
+        let Some(new_board) = self.try_make_move_new(chess_move) else {
+            return 0;
+        };
+
+        // Most valuable victim first, then least valuable attacker
+        let mut key: i32 = self.mvv_lva_score(chess_move);
+
+        // Captures that lose material are searched after the non-capturing moves
+        if key > 0 && self.see(chess_move) < 0 {
+            key -= LOSING_CAPTURE_SORT_PENALTY;
+        }
+
+        // Promotions are worth the value of the promoted piece
+        if let Some(promotion) = chess_move.0.get_promotion() {
+            key += PIECE_VALUES[promotion.to_index()] * 10;
+        }
+
+        // Checks are worth less than any capture
+        if *new_board.checkers() != chess::EMPTY {
+            key += CHECK_SORT_BONUS;
+        }
+
+        key
+    }
+
+    /// Check if the move is legal (supposedly very slow according to the chess crate).
+    /// Use this function for moves not generated by the move generator.
+    /// `is_legal_quick` is faster for moves generated by the move generator.
+    ///
+    /// ```python
+    /// >>> move = rust_chess.Move("e2e4")
+    /// >>> rust_chess.Board().is_legal_move(move)
+    /// True
+    /// >>> move2 = rust_chess.Move("e2e5")
+    /// >>> rust_chess.Board().is_legal_move(move2)
+    /// False
+    /// ```
+    #[inline]
+    fn is_legal_move(&self, chess_move: PyMove) -> bool {
+        // Check if the move is legal using the chess crate
+        chess::Board::legal(&self.board, chess_move.0)
+    }
+
+    /// Get a new board with the colors swapped and the board flipped vertically (e.g. a white pawn on e4 becomes a black pawn on e5).
+    /// The side to move, castling rights, and en passant square are swapped too, and the move counters are kept.
+    /// The new board has no move history.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("r3k2r/pp6/8/8/3pP3/8/8/R3K3 b Qkq e3 0 1")
+    /// >>> board.mirror()
+    /// r3k3/8/8/3Pp3/8/8/PP6/R3K2R w KQq e6 0 1
+    /// >>> board.mirror().mirror() == board
+    /// True
+    /// ```
+    #[inline]
+    fn mirror(&self) -> PyResult<PyBoard> {
+        let mut builder = chess::BoardBuilder::new();
+        for square in *self.board.combined() {
+            if let (Some(piece), Some(color)) = (self.board.piece_on(square), self.board.color_on(square)) {
+                builder.piece(PySquare(square).mirror().0, piece, !color);
+            }
+        }
+        builder.side_to_move(!self.board.side_to_move());
+        for color in chess::ALL_COLORS {
+            builder.castle_rights(!color, self.board.castle_rights(color));
+        }
+        builder.en_passant(self.board.en_passant().map(|square| square.get_file()));
+
+        let board = chess::Board::try_from(&builder).map_err(|_| PyValueError::new_err("Invalid board"))?;
+
+        let mut new_board = PyBoard::from_board(board, self.halfmove_clock, self.fullmove_number)?;
+        new_board.chess960 = self.chess960;
+        new_board.castling_rooks = self.castling_rooks.reverse_colors();
+
+        Ok(new_board)
+    }
+
+    /// Get the move corresponding to a move on the color-mirrored board (ranks flipped and colors swapped).
+    /// The promotion piece is kept, and castling moves map to the mirrored castling moves.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+    /// >>> move = board.mirror_move(rust_chess.Move("e7e5"))
+    /// >>> move
+    /// Move(e2, e4, None)
+    /// >>> rust_chess.Board("rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").is_legal_move(move)
+    /// True
+    /// ```
+    #[inline]
+    fn mirror_move(&self, chess_move: PyMove) -> PyMove {
+        PyMove(chess::ChessMove::new(
+            PySquare(chess_move.0.get_source()).mirror().0,
+            PySquare(chess_move.0.get_dest()).mirror().0,
+            chess_move.0.get_promotion(),
+        ))
+    }
+
+    /// Check if a move is legal, given either a move or a UCI string (e.g. "e2e4").
+    /// Raises a `ValueError` if the argument is neither a move nor a valid UCI string.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.is_legal(rust_chess.Move("e2e4"))
+    /// True
+    /// >>> board.is_legal("e2e4")
+    /// True
+    /// >>> board.is_legal("e2e5")
+    /// False
+    /// ```
+    #[inline]
+    fn is_legal(&self, move_or_uci: &Bound<'_, PyAny>) -> PyResult<bool> {
+        if let Ok(chess_move) = move_or_uci.extract::<PyMove>() {
+            return Ok(self.is_legal_move(chess_move));
+        }
+        // Otherwise, try treating the argument as a UCI string
+        if let Ok(uci) = move_or_uci.extract::<&str>() {
+            return Ok(self.is_legal_move(PyMove::from_uci(uci)?));
+        }
+        Err(PyValueError::new_err("Move must be a Move or a UCI string"))
+    }
+
+    /// Check if a move generated by the move generator is legal (much faster than `is_legal_move`).
+    /// Only use this function for moves generated by the move generator for the current board.
+    /// Passing any other move is undefined behavior (it may return a wrong answer or crash).
+    /// Use `is_legal_move` for moves not generated by the move generator.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> all(board.is_legal_quick(move) for move in board.generate_legal_moves())
+    /// True
+    /// ```
+    #[inline]
+    fn is_legal_quick(&self, chess_move: PyMove) -> bool {
+        // Check if the move is legal using the chess crate
+        chess::MoveGen::legal_quick(&self.board, chess_move.0)
+    }
+
+    /// Checks if a null move is reasonably safe for null-move pruning.
+    /// Returns False if the side to move is in check or only has a king and pawns (zugzwang-prone).
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().null_move_ok()
+    /// True
+    /// >>> rust_chess.Board("4k3/4p3/8/8/8/8/4P3/4K3 w - - 0 1").null_move_ok()
+    /// False
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").null_move_ok()
+    /// False
+    /// ```
+    #[inline]
+    fn null_move_ok(&self) -> bool {
+        // Get the pieces of the side to move other than the king and pawns
+        let king_and_pawns = self.board.pieces(chess::Piece::King) | self.board.pieces(chess::Piece::Pawn);
+        let non_pawn_pieces = self.board.color_combined(self.board.side_to_move()) & !king_and_pawns;
+
+        !self.is_check() && non_pawn_pieces != chess::EMPTY
+    }
+
+    /// Make a null move onto a new board.
+    /// Returns None if the current player is in check.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().make_null_move_new()
+    /// rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 1 1
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").make_null_move_new()
+    ///
+    /// ```
+    #[inline]
+    fn make_null_move_new(&self) -> PyResult<Option<Self>> {
+        // Get the new board using the chess crate
+        let Some(new_board) = self.board.null_move() else {
+            return Ok(None);
+        };
+
+        // Increment the halfmove clock
+        let halfmove_clock: u8 = self.halfmove_clock.saturating_add(1);
+
+        // Increment fullmove number if black moves
+        let fullmove_number: u16 = if self.board.side_to_move() == chess::Color::Black {
+            self.fullmove_number.saturating_add(1)
+        } else {
+            self.fullmove_number
+        };
+
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Create a new move generator using the chess crate
+        let move_gen = Py::new(py, PyMoveGenerator::new(&new_board))?;
+
+        // Add the current state to the new board's move stack
+        let mut move_stack = self.move_stack.clone();
+        move_stack.push(self.get_state(PyMove::default()));
+
+        Ok(Some(PyBoard {
+            board: new_board,
+            move_gen,
+            halfmove_clock,
+            fullmove_number,
+            chess960: self.chess960,
+            castling_rooks: self.castling_rooks,
+            move_stack,
+            status: OnceLock::new(),
+        }))
+    }
+
+    /// Make a null move on the current board.
+    /// Returns whether the null move was made (False if the current player is in check).
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.make_null_move()
+    /// True
+    /// >>> board.turn
+    /// False
+    /// ```
+    #[inline]
+    fn make_null_move(&mut self) -> PyResult<bool> {
+        // Get the new board using the chess crate
+        let Some(new_board) = self.board.null_move() else {
+            return Ok(false);
+        };
+
+        // Add the current state (with the counters before the move) to the move stack
+        self.move_stack.push(self.get_state(PyMove::default()));
+
+        // Increment the halfmove clock
+        self.halfmove_clock = self.halfmove_clock.saturating_add(1);
+
+        // Increment fullmove number if black moves
+        if self.board.side_to_move() == chess::Color::Black {
+            self.fullmove_number = self.fullmove_number.saturating_add(1);
+        }
+
+        // Update the current board
+        self.board = new_board;
+        self.status = OnceLock::new();
+
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Create a new move generator using the chess crate
+        self.move_gen = Py::new(py, PyMoveGenerator::new(&new_board))?;
+
+        Ok(true)
+    }
+
+    /// Make a move onto a new board.
+    /// The new board has its own move generator, so the current board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> moves = board.generate_legal_moves()
+    /// >>> new_board = board.make_move_new(rust_chess.Move("e2e4"))
+    /// >>> rust_chess.Move("e2e4") in list(moves)
+    /// True
+    /// ```
+    #[pyo3(signature = (chess_move, check_legality = false))]
+    fn make_move_new(&self, chess_move: PyMove, check_legality: bool) -> PyResult<Self> {
+        // If we are checking legality, check if the move is legal
+        if check_legality && !self.is_legal_move(chess_move) {
+            return Err(PyValueError::new_err("Illegal move"));
+        }
+
+        // Make the move onto a new board using the chess crate
+        let new_board: chess::Board = self.board.make_move_new(chess_move.0);
+
+        // Reset the halfmove clock if the move zeroes (is a capture or pawn move and therefore "zeroes" the halfmove clock)
+        let halfmove_clock: u8 = if self.is_zeroing(chess_move) {
+            0
+        } else {
+            self.halfmove_clock.saturating_add(1)
+        };
+
+        // Increment fullmove number if black moves
+        let fullmove_number: u16 = if self.board.side_to_move() == chess::Color::Black {
+            self.fullmove_number.saturating_add(1)
+        } else {
+            self.fullmove_number
+        };
+
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Create a new move generator using the chess crate
+        let move_gen = Py::new(py, PyMoveGenerator::new(&new_board))?;
+
+        // Add the current state to the new board's move stack
+        let mut move_stack = self.move_stack.clone();
+        move_stack.push(self.get_state(chess_move));
+
+        Ok(PyBoard {
+            board: new_board,
+            move_gen,
+            halfmove_clock,
+            fullmove_number,
+            chess960: self.chess960,
+            castling_rooks: self.get_castling_rooks_after(chess_move),
+            move_stack,
+            status: OnceLock::new(),
+        })
+    }
+
+    /// Make a move onto a new board and get the SAN of the move in the current position.
+    /// Raises a `ValueError` if the move is illegal.
+    ///
+    /// ```python
+    /// >>> board, san = rust_chess.Board().play(rust_chess.Move("g1f3"))
+    /// >>> san
+    /// 'Nf3'
+    /// >>> board
+    /// rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKB1R b KQkq - 1 1
+    /// ```
+    #[inline]
+    fn play(&self, chess_move: PyMove) -> PyResult<(PyBoard, String)> {
+        let new_board = self.make_move_new(chess_move, true)?;
+        let san = self.san(chess_move, true)?;
+
+        Ok((new_board, san))
+    }
+
+    /// Make a move on the current board.
+    /// The board gets a new move generator for the new position.
+    /// A generator from before the move is not invalidated and keeps yielding the old position's moves.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> moves = board.generate_legal_moves()
+    /// >>> board.make_move(rust_chess.Move("e2e4"))
+    /// >>> len(list(moves))
+    /// 20
+    /// >>> len(list(board.generate_legal_moves()))
+    /// 20
+    /// >>> rust_chess.Move("e7e5") in board.get_legal_moves()
+    /// True
+    /// ```
+    #[pyo3(signature = (chess_move, check_legality = false))]
+    fn make_move(&mut self, chess_move: PyMove, check_legality: bool) -> PyResult<()> {
+        // If we are checking legality, check if the move is legal
+        if check_legality && !self.is_legal_move(chess_move) {
+            return Err(PyValueError::new_err("Illegal move"));
+        }
+
+        // Make the move onto a new board using the chess crate
+        let temp_board: chess::Board = self.board.make_move_new(chess_move.0);
+
+        // Add the current state (with the counters before the move) to the move stack
+        self.move_stack.push(self.get_state(chess_move));
+
+        // Reset the halfmove clock if the move zeroes (is a capture or pawn move and therefore "zeroes" the halfmove clock)
+        self.halfmove_clock = if self.is_zeroing(chess_move) {
+            0
+        } else {
+            self.halfmove_clock.saturating_add(1)
+        };
+
+        // Increment fullmove number if black moves
+        if self.board.side_to_move() == chess::Color::Black {
+            self.fullmove_number = self.fullmove_number.saturating_add(1);
+        }
+
+        // Update the current board
+        self.castling_rooks = self.get_castling_rooks_after(chess_move);
+        self.board = temp_board;
+        self.status = OnceLock::new();
+
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Create a new move generator using the chess crate
+        self.move_gen = Py::new(py, PyMoveGenerator::new(&temp_board))?;
+
+        Ok(())
+    }
+
+    /// Make a move on the current board and add it to the move stack.
+    /// Same as `make_move`.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.push(rust_chess.Move("e2e4"))
+    /// >>> board
+    /// rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1
+    /// ```
+    #[inline]
+    #[pyo3(signature = (chess_move, check_legality = false))]
+    fn push(&mut self, chess_move: PyMove, check_legality: bool) -> PyResult<()> {
+        self.make_move(chess_move, check_legality)
+    }
+
+    /// Make a move from a UCI string (e.g. "e2e4") on the current board and return the move.
+    /// Raises a `ValueError` if the UCI string is invalid or the move is illegal.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.push_uci("e2e4")
+    /// Move(e2, e4, None)
+    /// >>> board.push_uci("e2e4")
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Illegal move
+    /// ```
+    #[inline]
+    fn push_uci(&mut self, uci: &str) -> PyResult<PyMove> {
+        let chess_move = PyMove::from_uci(uci)?;
+        self.make_move(chess_move, true)?;
+        Ok(chess_move)
+    }
+
+    /// Make a move from a SAN string (e.g. "Nf3") on the current board and return the move.
+    /// Raises a `ValueError` if the SAN string doesn't match exactly one legal move.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> for san in ["e4", "e5", "Nf3"]:
+    /// ...     _ = board.push_san(san)
+    /// >>> board
+    /// rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2
+    /// ```
+    #[inline]
+    fn push_san(&mut self, san: &str) -> PyResult<PyMove> {
+        // The SAN string is only matched against legal moves, so the legality is already checked
+        let chess_move = self.parse_san(san)?;
+        self.make_move(chess_move, false)?;
+        Ok(chess_move)
+    }
+
+    /// Undo the last move on the move stack and return it.
+    /// Restores the previous position, move counters, and move generator.
+    /// A null move is returned as `Move(a1, a1, None)`.
+    /// Raises a `ValueError` if there are no moves to undo.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.push(rust_chess.Move("e2e4"))
+    /// >>> board.pop()
+    /// Move(e2, e4, None)
+    /// >>> board
+    /// rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+    /// ```
+    #[inline]
+    fn pop(&mut self) -> PyResult<PyMove> {
+        let Some(state) = self.move_stack.pop() else {
+            return Err(PyValueError::new_err("No moves to undo"));
+        };
+
+        // Restore the previous state
+        self.board = state.board;
+        self.castling_rooks = state.castling_rooks;
+        self.halfmove_clock = state.halfmove_clock;
+        self.fullmove_number = state.fullmove_number;
+        self.status = OnceLock::new();
+
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Create a new move generator using the chess crate
+        self.move_gen = Py::new(py, PyMoveGenerator::new(&self.board))?;
+
+        Ok(state.chess_move)
+    }
+
+    /// Get the last move on the move stack without undoing it, otherwise None.
+    /// A null move is returned as `Move(a1, a1, None)`.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.peek()
+    ///
+    /// >>> board.push(rust_chess.Move("e2e4"))
+    /// >>> board.peek()
+    /// Move(e2, e4, None)
+    /// ```
+    #[inline]
+    fn peek(&self) -> Option<PyMove> {
+        self.move_stack.last().map(|state| state.chess_move)
+    }
+
+    /// Get the bitboard of the side to move's pinned pieces
+    #[inline]
+    fn get_pinned_bitboard(&self) -> PyBitboard {
+        PyBitboard(*self.board.pinned())
+    }
+
+    /// Get the bitboard of the side to move's pieces that are pinned to their king.
+    /// Same as `get_pinned_bitboard`.
+    ///
+    /// ```python
+    /// >>> list(rust_chess.Board("4r1k1/8/8/8/8/8/4N3/4K3 w - - 0 1").pinned())
+    /// [e2]
+    /// ```
+    #[inline]
+    fn pinned(&self) -> PyBitboard {
+        self.get_pinned_bitboard()
+    }
+
+    /// Checks if the piece on a square is one of the side to move's pieces pinned to their king.
+    /// The opponent's pieces are never reported as pinned.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4r1k1/8/8/8/8/8/4N3/4K3 w - - 0 1")
+    /// >>> board.is_pinned(rust_chess.E2)
+    /// True
+    /// >>> board.is_pinned(rust_chess.E1)
+    /// False
+    /// ```
+    #[inline]
+    fn is_pinned(&self, square: PySquare) -> bool {
+        self.board.pinned() & chess::BitBoard::from_square(square.0) != chess::EMPTY
+    }
+
+    /// Get the bitboard of the pieces putting the side to move in check
+    #[inline]
+    fn get_checkers_bitboard(&self) -> PyBitboard {
+        PyBitboard(*self.board.checkers())
+    }
+
+    /// Get the bitboard of the pieces putting the side to move in check.
+    /// Same as `get_checkers_bitboard`.
+    ///
+    /// ```python
+    /// >>> list(rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").checkers())
+    /// [h4]
+    /// ```
+    #[inline]
+    fn checkers(&self) -> PyBitboard {
+        self.get_checkers_bitboard()
+    }
+
+    /// Checks if the side to move is in check from two pieces at once.
+    /// Only king moves can escape a double check.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board("4k3/8/5N2/8/8/8/8/4R1K1 b - - 0 1").is_double_check()
+    /// True
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_double_check()
+    /// False
+    /// ```
+    #[inline]
+    fn is_double_check(&self) -> bool {
+        self.board.checkers().popcnt() >= 2
+    }
+
+    /// Get a list of the squares of the pieces putting the side to move in check, ordered by square.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board("4k3/8/5N2/8/8/8/8/4R1K1 b - - 0 1").checking_squares()
+    /// [e1, f6]
+    /// >>> rust_chess.Board().checking_squares()
+    /// []
+    /// ```
+    #[inline]
+    fn checking_squares(&self) -> Vec<PySquare> {
+        self.board.checkers().map(PySquare).collect()
+    }
+
+    /// Get the bitboard of all the pieces
+    #[inline]
+    fn get_all_bitboard(&self) -> PyBitboard {
+        PyBitboard(*self.board.combined())
+    }
+
+    /// Get the bitboard of all the pieces of a certain color
+    #[inline]
+    fn get_color_bitboard(&self, color: PyColor) -> PyBitboard {
+        PyBitboard(*self.board.color_combined(color.0))
+    }
+
+    /// Get the bitboard of all the pieces of a certain type
+    #[inline]
+    fn get_piece_type_bitboard(&self, piece_type: PyPieceType) -> PyBitboard {
+        PyBitboard(*self.board.pieces(piece_type.0))
+    }
+
+    /// Get the bitboard of all the pieces of a certain color and type
+    #[inline]
+    fn get_piece_bitboard(&self, piece: PyPiece) -> PyBitboard {
+        PyBitboard(self.board.pieces(piece.piece_type.0) & self.board.color_combined(piece.color.0))
+    }
+
+    /// Get the bitboard of all the pieces.
+    /// Same as `get_all_bitboard`.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().combined().popcnt()
+    /// 32
+    /// ```
+    #[inline]
+    fn combined(&self) -> PyBitboard {
+        self.get_all_bitboard()
+    }
+
+    /// Get the bitboard of all the pieces of a certain color.
+    /// Same as `get_color_bitboard`.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.color_combined(rust_chess.WHITE) & board.color_combined(rust_chess.BLACK) == rust_chess.Bitboard(0)
+    /// True
+    /// ```
+    #[inline]
+    fn color_combined(&self, color: PyColor) -> PyBitboard {
+        self.get_color_bitboard(color)
+    }
+
+    /// Get the bitboard of all the pieces of a certain type (both colors).
+    /// Same as `get_piece_type_bitboard`.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().pieces(rust_chess.PAWN).popcnt()
+    /// 16
+    /// ```
+    #[inline]
+    fn pieces(&self, piece_type: PyPieceType) -> PyBitboard {
+        self.get_piece_type_bitboard(piece_type)
+    }
+
+    /// Get the bitboard of the pieces of a certain type and color.
+    ///
+    /// ```python
+    /// >>> list(rust_chess.Board().pieces_colored(rust_chess.ROOK, rust_chess.WHITE))
+    /// [a1, h1]
+    /// ```
+    #[inline]
+    fn pieces_colored(&self, piece_type: PyPieceType, color: PyColor) -> PyBitboard {
+        self.get_piece_bitboard(PyPiece { piece_type, color })
+    }
+
+    /// Get the bitboard of the pawns of a certain color attacking a square.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4k3/8/8/2p1p3/8/8/8/4K3 w - - 0 1")
+    /// >>> list(board.pawn_attackers(rust_chess.BLACK, rust_chess.D4))
+    /// [c5, e5]
+    /// ```
+    #[inline]
+    fn pawn_attackers(&self, color: PyColor, square: PySquare) -> PyBitboard {
+        let pawns = self.board.pieces(chess::Piece::Pawn) & self.board.color_combined(color.0);
+
+        // A pawn attacks the square if a pawn of the other color on the square would attack it
+        PyBitboard(chess::get_pawn_attacks(square.0, !color.0, pawns))
+    }
 
-        // Reset the halfmove clock if the move zeroes (is a capture or pawn move and therefore "zeroes" the halfmove clock)
-        let halfmove_clock: u8 = if self.is_zeroing(chess_move) {
-            0
-        } else {
-            self.halfmove_clock + 1
+    /// Check if a square is attacked by any pawn of a certain color.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4k3/8/8/2p1p3/8/8/8/4K3 w - - 0 1")
+    /// >>> board.is_pawn_attacked(rust_chess.BLACK, rust_chess.D4)
+    /// True
+    /// >>> board.is_pawn_attacked(rust_chess.BLACK, rust_chess.D5)
+    /// False
+    /// ```
+    #[inline]
+    fn is_pawn_attacked(&self, color: PyColor, square: PySquare) -> bool {
+        self.pawn_attackers(color, square).0 != chess::EMPTY
+    }
+
+    /// Get the bitboard of the pieces of a certain color attacking a square.
+    /// Sliding pieces are blocked by pieces in the way.
+    ///
+    /// ```python
+    /// >>> list(rust_chess.Board().attackers(rust_chess.WHITE, rust_chess.F3))
+    /// [g1, e2, g2]
+    /// ```
+    #[inline]
+    fn attackers(&self, color: PyColor, square: PySquare) -> PyBitboard {
+        PyBitboard(self.get_attackers(color.0, square.0, *self.board.combined()))
+    }
+
+    /// Check if a square is attacked by any piece of a certain color.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.is_attacked(rust_chess.F3, rust_chess.WHITE)
+    /// True
+    /// >>> board.is_attacked(rust_chess.E4, rust_chess.WHITE)
+    /// False
+    /// ```
+    #[inline]
+    fn is_attacked(&self, square: PySquare, by_color: PyColor) -> bool {
+        self.attackers(by_color, square).0 != chess::EMPTY
+    }
+
+    /// Get the bitboard of the outpost squares of a certain color.
+    /// An outpost is a square in the opponent's half of the board that is defended by a friendly pawn
+    /// and can never be attacked by an enemy pawn (no enemy pawns on the adjacent files ahead of it).
+    /// Squares are included whether or not they are occupied.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("r1bqkb1r/pp3ppp/2np1n2/4p3/4P3/2N5/PPP1BPPP/R1BQK1NR w KQkq - 0 7")
+    /// >>> list(board.outposts(rust_chess.WHITE))
+    /// [d5]
+    /// ```
+    #[inline]
+    fn outposts(&self, color: PyColor) -> PyBitboard {
+        let pawns = self.board.pieces(chess::Piece::Pawn);
+        let friendly_pawns = pawns & self.board.color_combined(color.0);
+        let enemy_pawns = pawns & self.board.color_combined(!color.0);
+
+        // Get the opponent's half of the board
+        let opponent_half = match color.0 {
+            chess::Color::White => chess::BitBoard(0xFFFF_FFFF_0000_0000),
+            chess::Color::Black => chess::BitBoard(0x0000_0000_FFFF_FFFF),
+        };
+
+        let mut outposts = chess::EMPTY;
+        for square in opponent_half {
+            // The square must be defended by a friendly pawn
+            if chess::get_pawn_attacks(square, !color.0, friendly_pawns) == chess::EMPTY {
+                continue;
+            }
+
+            // Get the ranks ahead of the square from the color's perspective
+            let rank_index = square.get_rank().to_index();
+            let ranks_ahead = match color.0 {
+                chess::Color::White => chess::BitBoard(!0u64 << (8 * rank_index) << 8),
+                chess::Color::Black => chess::BitBoard((1u64 << (8 * rank_index)) - 1),
+            };
+
+            // No enemy pawns can ever attack the square
+            if chess::get_adjacent_files(square.get_file()) & ranks_ahead & enemy_pawns == chess::EMPTY {
+                outposts |= chess::BitBoard::from_square(square);
+            }
+        }
+
+        PyBitboard(outposts)
+    }
+
+    // TODO: set_iterator_mask, will have to implement PyBitboard
+
+    /// Remove a move from the move generator.
+    /// Prevents the move from being generated.
+    /// Useful if you already have a certain move and don't need to generate it again.
+    ///
+    #[inline]
+    fn remove_move(&mut self, chess_move: PyMove) {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Remove the move from the generator
+        self.move_gen.borrow_mut(py).remove_move(chess_move.0);
+    }
+
+    /// Remove the moves onto squares in a mask from the move generator.
+    /// Prevents the moves from being generated until the move generator is reset.
+    /// Useful for skipping a group of moves (e.g. all captures by removing the enemy pieces).
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.remove_move_mask(rust_chess.Bitboard(rust_chess.E4))
+    /// >>> moves = list(board.generate_legal_moves())
+    /// >>> len(moves)
+    /// 19
+    /// >>> any(move.dest == rust_chess.E4 for move in moves)
+    /// False
+    /// ```
+    #[inline]
+    fn remove_move_mask(&mut self, mask: PyBitboard) {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Remove the moves onto the mask from the generator
+        self.move_gen.borrow_mut(py).remove_mask(mask.0);
+    }
+
+    /// Reset the move generator for the current board
+    #[inline]
+    fn reset_move_generator(&mut self) -> PyResult<()> {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Create a new move generator using the chess crate
+        self.move_gen = Py::new(py, PyMoveGenerator::new(&self.board))?;
+
+        Ok(())
+    }
+
+    /// Get the next remaining move of the generator.
+    /// Updates the move generator to the next move.
+    /// Unless the mask is set, this will return the next legal move by default.
+    ///
+    #[inline]
+    fn next_move(&mut self) -> Option<PyMove> {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Get the next move from the generator
+        self.move_gen.borrow_mut(py).__next__()
+    }
+
+    /// Get up to `n` of the next remaining moves of the generator as a list.
+    /// Updates the move generator, so repeated calls stream through the remaining moves.
+    /// Returns an empty list once the move generator is exhausted.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> len(board.take_moves(15))
+    /// 15
+    /// >>> len(board.take_moves(15))
+    /// 5
+    /// >>> board.take_moves(15)
+    /// []
+    /// ```
+    #[inline]
+    fn take_moves(&mut self, n: usize) -> Vec<PyMove> {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Get the next moves from the generator
+        let mut move_gen = self.move_gen.borrow_mut(py);
+        std::iter::from_fn(|| move_gen.__next__()).take(n).collect()
+    }
+
+    /// Get a list of all legal moves for the current board.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> len(board.get_legal_moves())
+    /// 20
+    /// >>> board.get_legal_moves() == board.get_legal_moves()
+    /// True
+    /// ```
+    #[inline]
+    fn get_legal_moves(&self) -> Vec<PyMove> {
+        // Generate the legal moves using the chess crate
+        chess::MoveGen::new_legal(&self.board).map(PyMove).collect()
+    }
+
+    /// Count the leaf nodes of the legal move tree to a depth (perft).
+    /// Useful for validating and benchmarking move generation against known values.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().perft(3)
+    /// 8902
+    /// >>> rust_chess.Board("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").perft(2)
+    /// 2039
+    /// ```
+    #[inline]
+    fn perft(&self, depth: u32) -> u64 {
+        perft(&self.board, depth)
+    }
+
+    /// Count the leaf nodes of the legal move tree to a depth (perft), using multiple threads.
+    /// The legal moves are split across threads, and the GIL is released while counting.
+    /// Gives the same result as `perft`.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.perft_parallel(4) == board.perft(4)
+    /// True
+    /// ```
+    #[inline]
+    fn perft_parallel(&self, depth: u32) -> u64 {
+        if depth <= 1 {
+            return perft(&self.board, depth);
+        }
+
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Release the GIL while the threads count the nodes under each legal move
+        let board = self.board;
+        py.allow_threads(|| {
+            let moves: Vec<chess::ChessMove> = chess::MoveGen::new_legal(&board).collect();
+            moves
+                .par_iter()
+                .map(|&chess_move| perft(&board.make_move_new(chess_move), depth - 1))
+                .sum()
+        })
+    }
+
+    /// Count the leaf nodes under each legal move to a depth (perft divide).
+    /// Returns a map from the UCI string of each legal move to its node count at one less depth (ordered by UCI string),
+    /// and the total node count (the same as `perft`).
+    /// At depth 0 there are no moves to divide, so the map is empty and the total is 1.
+    /// Useful for finding move generation differences against a reference engine.
+    ///
+    /// ```python
+    /// >>> divide, total = rust_chess.Board().perft_divide(2)
+    /// >>> len(divide), set(divide.values())
+    /// (20, {20})
+    /// >>> divide["e2e4"]
+    /// 20
+    /// >>> total
+    /// 400
+    /// >>> rust_chess.Board().perft_divide(0)
+    /// ({}, 1)
+    /// ```
+    #[inline]
+    fn perft_divide(&self, depth: u32) -> (BTreeMap<String, u64>, u64) {
+        // The root position is the only node at depth 0
+        if depth == 0 {
+            return (BTreeMap::new(), 1);
+        }
+
+        let divide: BTreeMap<String, u64> = chess::MoveGen::new_legal(&self.board)
+            .map(|chess_move| {
+                let nodes = perft(&self.board.make_move_new(chess_move), depth - 1);
+                (chess_move.to_string(), nodes)
+            })
+            .collect();
+        let total = divide.values().sum();
+
+        (divide, total)
+    }
+
+    /// Clear a list and fill it with all legal moves for the current board.
+    /// Reusing the same list avoids creating a new list for every position.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> moves = []
+    /// >>> board = rust_chess.Board()
+    /// >>> board.fill_legal_moves(moves)
+    /// >>> moves == board.get_legal_moves()
+    /// True
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").fill_legal_moves(moves)
+    /// >>> moves
+    /// []
+    /// ```
+    #[inline]
+    fn fill_legal_moves(&self, out: &Bound<'_, PyList>) -> PyResult<()> {
+        // Clear the list
+        out.del_slice(0, out.len())?;
+
+        // Generate the legal moves using the chess crate and append them to the list
+        for chess_move in chess::MoveGen::new_legal(&self.board) {
+            out.append(PyMove(chess_move))?;
+        }
+
+        Ok(())
+    }
+
+    /// Count the legal moves for the current board without creating a list of moves.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().count_legal_moves()
+    /// 20
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").count_legal_moves()
+    /// 0
+    /// ```
+    #[inline]
+    fn count_legal_moves(&self) -> usize {
+        chess::MoveGen::new_legal(&self.board).len()
+    }
+
+    /// Get a dictionary of each square with a piece of the side to move to a bitboard of its legal destinations.
+    /// Pinned pieces and check are accounted for, so pieces that can't move have an empty bitboard.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> destinations = rust_chess.Board("4k3/4r3/8/8/8/8/4R3/1N2K3 w - - 0 1").legal_destination_map()
+    /// >>> list(destinations[rust_chess.E2])
+    /// [e3, e4, e5, e6, e7]
+    /// >>> list(destinations[rust_chess.B1])
+    /// [d2, a3, c3]
+    /// ```
+    #[inline]
+    fn legal_destination_map(&self) -> BTreeMap<PySquare, PyBitboard> {
+        // Start with an empty bitboard for every piece of the side to move
+        let mut destinations: BTreeMap<PySquare, PyBitboard> = self
+            .board
+            .color_combined(self.board.side_to_move())
+            .map(|square| (PySquare(square), PyBitboard(chess::EMPTY)))
+            .collect();
+
+        // Add the destination of each legal move to its source square
+        for chess_move in chess::MoveGen::new_legal(&self.board) {
+            if let Some(bitboard) = destinations.get_mut(&PySquare(chess_move.get_source())) {
+                bitboard.0 |= chess::BitBoard::from_square(chess_move.get_dest());
+            }
+        }
+
+        destinations
+    }
+
+    /// Get a random legal move, otherwise None if there are no legal moves.
+    /// Each legal move is equally likely, and the same seed always gives the same move.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> board.random_move(seed=42) == board.random_move(seed=42)
+    /// True
+    /// >>> board.random_move() in board.get_legal_moves()
+    /// True
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").random_move()
+    ///
+    /// ```
+    #[inline]
+    #[pyo3(signature = (seed = None))]
+    fn random_move(&self, seed: Option<u64>) -> Option<PyMove> {
+        let moves: Vec<chess::ChessMove> = chess::MoveGen::new_legal(&self.board).collect();
+        if moves.is_empty() {
+            return None;
+        }
+
+        Some(PyMove(moves[Rng::new(seed).index(moves.len())]))
+    }
+
+    /// Play random legal moves on the board until the game is over or a number of plies is reached.
+    /// Returns the list of moves played, which stay on the move stack.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> moves = board.play_random_game(20, seed=42)
+    /// >>> len(moves)
+    /// 20
+    /// >>> replay = rust_chess.Board()
+    /// >>> for move in moves:
+    /// ...     replay.push(move)
+    /// >>> replay.get_fen() == board.get_fen()
+    /// True
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").play_random_game(10)
+    /// []
+    /// ```
+    #[pyo3(signature = (max_plies, seed = None))]
+    fn play_random_game(&mut self, max_plies: u32, seed: Option<u64>) -> PyResult<Vec<PyMove>> {
+        let mut rng = Rng::new(seed);
+        let mut played = Vec::new();
+
+        // Checkmate and stalemate leave no legal moves, so only the status needs checking
+        while played.len() < max_plies as usize && matches!(self.get_status(), PyBoardStatus::Ongoing) {
+            let moves: Vec<chess::ChessMove> = chess::MoveGen::new_legal(&self.board).collect();
+            let chess_move = PyMove(moves[rng.index(moves.len())]);
+
+            self.make_move(chess_move, false)?;
+            played.push(chess_move);
+        }
+
+        Ok(played)
+    }
+
+    /// Get a list of the legal moves originating from a square.
+    /// Returns an empty list immediately if the square is empty or holds a piece of the side not to move.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().moves_from(rust_chess.G1)
+    /// [Move(g1, f3, None), Move(g1, h3, None)]
+    /// >>> rust_chess.Board().moves_from(rust_chess.E4)
+    /// []
+    /// ```
+    #[inline]
+    fn moves_from(&self, square: PySquare) -> Vec<PyMove> {
+        // Short-circuit if the square doesn't hold a piece of the side to move
+        if self.board.color_on(square.0) != Some(self.board.side_to_move()) {
+            return Vec::new();
+        }
+
+        // Generate the legal moves using the chess crate and keep the ones from the square
+        chess::MoveGen::new_legal(&self.board)
+            .filter(|chess_move| chess_move.get_source() == square.0)
+            .map(PyMove)
+            .collect()
+    }
+
+    /// Get a list of the legal moves originating from a square.
+    /// Same as `moves_from`.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().legal_moves_from(rust_chess.G1)
+    /// [Move(g1, f3, None), Move(g1, h3, None)]
+    /// >>> rust_chess.Board().legal_moves_from(rust_chess.E4)
+    /// []
+    /// ```
+    #[inline]
+    fn legal_moves_from(&self, square: PySquare) -> Vec<PyMove> {
+        self.moves_from(square)
+    }
+
+    /// Get a list of the legal moves made by pieces of a type for the side to move.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> len(board.legal_moves_for(rust_chess.KNIGHT))
+    /// 4
+    /// >>> len(board.legal_moves_for(rust_chess.PAWN))
+    /// 16
+    /// >>> board.legal_moves_for(rust_chess.QUEEN)
+    /// []
+    /// ```
+    #[inline]
+    fn legal_moves_for(&self, piece_type: PyPieceType) -> Vec<PyMove> {
+        // Squares of the pieces of the type for the side to move
+        let sources = self.board.pieces(piece_type.0) & self.board.color_combined(self.board.side_to_move());
+
+        // Generate the legal moves using the chess crate and keep the ones from those squares
+        chess::MoveGen::new_legal(&self.board)
+            .filter(|chess_move| sources & chess::BitBoard::from_square(chess_move.get_source()) != chess::EMPTY)
+            .map(PyMove)
+            .collect()
+    }
+
+    /// Get a list of the legal promotion moves (including capture promotions).
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("1n5k/P7/8/8/8/8/8/K7 w - - 0 1")
+    /// >>> moves = board.legal_promotions()
+    /// >>> moves[:4]
+    /// [Move(a7, a8, QUEEN), Move(a7, a8, KNIGHT), Move(a7, a8, ROOK), Move(a7, a8, BISHOP)]
+    /// >>> moves[4:]
+    /// [Move(a7, b8, QUEEN), Move(a7, b8, KNIGHT), Move(a7, b8, ROOK), Move(a7, b8, BISHOP)]
+    /// >>> rust_chess.Board().legal_promotions()
+    /// []
+    /// ```
+    #[inline]
+    fn legal_promotions(&self) -> Vec<PyMove> {
+        // Only pawns on the rank before the last rank can promote
+        let pawns = self.board.pieces(chess::Piece::Pawn) & self.board.color_combined(self.board.side_to_move());
+        let promoting_pawns = pawns & chess::get_rank(self.board.side_to_move().to_seventh_rank());
+        if promoting_pawns == chess::EMPTY {
+            return Vec::new();
+        }
+
+        // Generate the legal moves using the chess crate and keep the promotions
+        chess::MoveGen::new_legal(&self.board)
+            .filter(|chess_move| chess_move.get_promotion().is_some())
+            .map(PyMove)
+            .collect()
+    }
+
+    /// Get a list of the legal moves that get out of check (king moves, blocks, and captures of the checker).
+    /// Returns an empty list if the side to move is not in check.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("4k3/8/8/3N4/1b6/8/8/4K3 w - - 0 1")
+    /// >>> board.legal_evasions()
+    /// [Move(d5, c3, None), Move(d5, b4, None), Move(e1, d1, None), Move(e1, f1, None), Move(e1, e2, None), Move(e1, f2, None)]
+    /// >>> rust_chess.Board().legal_evasions()
+    /// []
+    /// ```
+    #[inline]
+    fn legal_evasions(&self) -> Vec<PyMove> {
+        if *self.board.checkers() == chess::EMPTY {
+            return Vec::new();
+        }
+
+        // All legal moves get out of check, so generate them using the chess crate
+        chess::MoveGen::new_legal(&self.board).map(PyMove).collect()
+    }
+
+    /// Generate the next remaining legal moves for the current board.
+    /// If the move generator is exhausted, it is reset first, so each call yields all legal moves
+    /// unless a previous iteration was stopped partway through.
+    /// Updates the move generator.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> len(list(board.generate_legal_moves()))
+    /// 20
+    /// >>> len(list(board.generate_legal_moves()))
+    /// 20
+    /// ```
+    #[inline]
+    fn generate_legal_moves(&mut self) -> PyResult<Py<PyMoveGenerator>> {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Set the iterator mask to everything (check all legal moves)
+        let exhausted = {
+            let mut move_gen = self.move_gen.borrow_mut(py);
+            move_gen.set_iterator_mask(!chess::EMPTY);
+            move_gen.__len__() == 0
         };
 
-        // Increment fullmove number if black moves
-        let fullmove_number: u8 = if self.board.side_to_move() == chess::Color::Black {
-            self.fullmove_number + 1
-        } else {
-            self.fullmove_number
-        };
+        // Rebuild the move generator if there are no remaining moves
+        if exhausted {
+            self.reset_move_generator()?;
+        }
+
+        // Share ownership with Python
+        Ok(self.move_gen.clone_ref(py))
+    }
+
+    #[inline]
+    /// Generate the next remaining legal captures for the current board.
+    /// Exhausts the move generator if fully iterated over.
+    /// Updates the move generator.
+    ///
+    fn generate_legal_captures(&mut self) -> Py<PyMoveGenerator> {
+        // Get the mask of enemy‐occupied squares
+        let targets_mask = self.board.color_combined(!self.board.side_to_move());
+
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        // Set the iterator mask to the targets mask (check all legal captures [moves onto enemy pieces])
+        self.move_gen
+            .borrow_mut(py)
+            .set_iterator_mask(*targets_mask);
+
+        // Share ownership with Python
+        self.move_gen.clone_ref(py)
+    }
+
+    /// Generate the next remaining legal quiet moves (non-captures) for the current board.
+    /// Includes castling and promotions onto empty squares.
+    /// En passant captures land on an empty square, so they are also included.
+    /// Exhausts the move generator if fully iterated over.
+    /// Updates the move generator.
+    ///
+    /// ```python
+    /// >>> len(list(rust_chess.Board().generate_quiet_moves()))
+    /// 20
+    /// ```
+    #[inline]
+    fn generate_quiet_moves(&mut self) -> Py<PyMoveGenerator> {
+        // Get the mask of empty squares
+        let targets_mask = !self.board.combined();
 
         // We can assume the GIL is acquired, since this function is only called from Python
         let py = unsafe { Python::assume_gil_acquired() };
 
-        // Create a new move generator using the chess crate
-        let move_gen = Py::new(py, PyMoveGenerator(chess::MoveGen::new_legal(&new_board)))?;
+        // Set the iterator mask to the targets mask (check all legal quiet moves [moves onto empty squares])
+        self.move_gen
+            .borrow_mut(py)
+            .set_iterator_mask(targets_mask);
 
-        Ok(PyBoard {
-            board: new_board,
-            move_gen,
-            halfmove_clock,
-            fullmove_number,
-        })
+        // Share ownership with Python
+        self.move_gen.clone_ref(py)
     }
 
-    /// Make a move on the current board
+    /// Checks if the side to move has insufficient material to checkmate the opponent.
+    /// The cases where this is true are:
+    ///     1. K vs K
+    ///     2. K vs K + N
+    ///     3. K vs K + B
+    ///     4. K + B vs K + B with the bishops on the same color.
     ///
-    #[pyo3(signature = (chess_move, check_legality = false))]
-    fn make_move(&mut self, chess_move: PyMove, check_legality: bool) -> PyResult<()> {
-        // If we are checking legality, check if the move is legal
-        if check_legality && !self.is_legal_move(chess_move) {
-            return Err(PyValueError::new_err("Illegal move"));
+    /// ```python
+    /// >>> rust_chess.Board().is_insufficient_material()
+    /// False
+    /// >>> rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K
+    /// True
+    /// >>> rust_chess.Board("4k3/8/8/8/5N2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + N
+    /// True
+    /// >>> rust_chess.Board("4k3/8/8/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + B
+    /// True
+    /// >>> rust_chess.Board("4k3/8/8/5b2/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B different color
+    /// False
+    /// >>> rust_chess.Board("4k3/8/5b2/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B same color
+    /// True
+    /// ```
+    #[inline]
+    fn is_insufficient_material(&self) -> bool {
+        let kings = self.board.pieces(chess::Piece::King);
+
+        // Get the bitboards of the white and black pieces without the kings
+        let white_bb = self.board.color_combined(chess::Color::White) & !kings;
+        let black_bb = self.board.color_combined(chess::Color::Black) & !kings;
+        let combined_bb = white_bb | black_bb;
+
+        // King vs King: Combined bitboard minus kings is empty
+        if combined_bb == chess::EMPTY {
+            return true;
         }
 
-        // Make the move onto a new board using the chess crate
-        let temp_board: chess::Board = self.board.make_move_new(chess_move.0);
+        let remaining_num_pieces = combined_bb.popcnt();
 
-        // Reset the halfmove clock if the move zeroes (is a capture or pawn move and therefore "zeroes" the halfmove clock)
-        self.halfmove_clock = if self.is_zeroing(chess_move) {
-            0
-        } else {
-            self.halfmove_clock + 1
+        if remaining_num_pieces <= 2 {
+            let knights = self.board.pieces(chess::Piece::Knight);
+            let bishops = self.board.pieces(chess::Piece::Bishop);
+
+            // King vs King + Knight/Bishop: Combined bitboard minus kings and knight/bishop is empty
+            if remaining_num_pieces == 1 && combined_bb & !(knights | bishops) == chess::EMPTY {
+                return true;
+            } else if *knights == chess::EMPTY {
+                // Only bishops left
+                let white_bishops = bishops & white_bb;
+                let black_bishops = bishops & black_bb;
+
+                if white_bishops != chess::EMPTY && black_bishops != chess::EMPTY // Both sides have a bishop
+                    // King + Bishop vs King + Bishop same color: White and black bishops are on the same color square
+                    && PySquare(white_bishops.to_square()).get_color() == PySquare(black_bishops.to_square()).get_color()
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Checks if the halfmoves since the last pawn move or capture is >= 100
+    /// and the game is ongoing (not checkmate or stalemate).
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().is_fifty_moves
+    /// False
+    /// >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 50 1").is_fifty_moves()
+    /// True
+    /// ```
+    #[inline]
+    fn is_fifty_moves(&self) -> bool {
+        self.halfmove_clock >= 100 && self.has_legal_moves()
+    }
+
+    /// Checks if the halfmoves since the last pawn move or capture is >= 150
+    /// and the game is ongoing (not checkmate or stalemate).
+    ///
+    #[inline]
+    fn is_seventy_five_moves(&self) -> bool {
+        self.halfmove_clock >= 150 && self.has_legal_moves()
+    }
+
+    /// Checks if the side to move can claim a draw by the fifty-move rule
+    /// (halfmoves since the last pawn move or capture is >= 100).
+    /// Unlike the seventy-five-move rule in `get_status`, this draw is not automatic and must be claimed.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().can_claim_fifty_moves()
+    /// False
+    /// >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").can_claim_fifty_moves()
+    /// True
+    /// ```
+    #[inline]
+    fn can_claim_fifty_moves(&self) -> bool {
+        self.halfmove_clock >= 100
+    }
+
+    /// Checks if the side to move can claim a draw by threefold repetition
+    /// (the current position has occurred at least three times).
+    /// Unlike fivefold repetition in `get_status`, this draw is not automatic and must be claimed.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> for _ in range(2):
+    /// ...     for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+    /// ...         board.make_move(rust_chess.Move(uci))
+    /// >>> board.can_claim_threefold_repetition()
+    /// True
+    /// ```
+    #[inline]
+    fn can_claim_threefold_repetition(&self) -> bool {
+        self.get_repetition_count() >= 3
+    }
+
+    /// Checks if the side to move can claim a draw by the fifty-move rule or threefold repetition.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().can_claim_draw()
+    /// False
+    /// >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").can_claim_draw()
+    /// True
+    /// ```
+    #[inline]
+    fn can_claim_draw(&self) -> bool {
+        self.can_claim_fifty_moves() || self.can_claim_threefold_repetition()
+    }
+
+    /// Checks if the current position has occurred at least five times.
+    /// Only positions since the last pawn move or capture are considered,
+    /// since positions before an irreversible move cannot repeat.
+    /// Positions before the board was created (e.g. from a FEN string) are unknown.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> for _ in range(4):
+    /// ...     for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+    /// ...         board.make_move(rust_chess.Move(uci))
+    /// >>> board.is_fivefold_repetition()
+    /// True
+    /// ```
+    #[inline]
+    fn is_fivefold_repetition(&self) -> bool {
+        self.get_repetition_count() >= 5
+    }
+
+    /// Checks if the current position has occurred at least a number of times (including the current position).
+    /// Only positions since the last pawn move or capture are considered.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+    /// ...     board.make_move(rust_chess.Move(uci))
+    /// >>> board.is_repetition(2), board.is_repetition(3)
+    /// (True, False)
+    /// >>> for uci in ["g1f3", "g8f6", "f3g1", "f6g8"]:
+    /// ...     board.make_move(rust_chess.Move(uci))
+    /// >>> board.is_repetition(3)
+    /// True
+    /// ```
+    #[inline]
+    fn is_repetition(&self, count: usize) -> bool {
+        self.get_repetition_count() >= count
+    }
+
+    /// Checks if the side to move is in check.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().is_check
+    /// False
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_check()
+    /// True
+    /// ```
+    #[inline]
+    fn is_check(&self) -> bool {
+        *self.board.checkers() != chess::EMPTY
+    }
+
+    /// Checks if the side to move is in stalemate
+    #[inline]
+    fn is_stalemate(&self) -> bool {
+        self.get_status() == PyBoardStatus::Stalemate
+    }
+
+    /// Checks if the side to move is in checkmate
+    #[inline]
+    fn is_checkmate(&self) -> bool {
+        self.get_status() == PyBoardStatus::Checkmate
+    }
+
+    /// Checks if the position is quiet: the side to move is not in check
+    /// and has no legal captures (including en passant) or promotions.
+    /// Quiet positions are stable enough to evaluate statically.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().is_quiet()
+    /// True
+    /// >>> rust_chess.Board("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2").is_quiet()
+    /// False
+    /// ```
+    #[inline]
+    fn is_quiet(&self) -> bool {
+        !self.is_check()
+            && !chess::MoveGen::new_legal(&self.board).any(|chess_move| {
+                chess_move.get_promotion().is_some()
+                    || self.get_captured_piece_type(PyMove(chess_move)).is_some()
+            })
+    }
+
+    /// Get a list of the legal moves that immediately checkmate the opponent.
+    /// Uses a separate move generator, so the board's move generator is not updated.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").checkmate_moves()
+    /// [Move(a1, a8, None)]
+    /// >>> rust_chess.Board().checkmate_moves()
+    /// []
+    /// ```
+    #[inline]
+    fn checkmate_moves(&self) -> Vec<PyMove> {
+        chess::MoveGen::new_legal(&self.board)
+            .filter(|&chess_move| {
+                self.board.make_move_new(chess_move).status() == chess::BoardStatus::Checkmate
+            })
+            .map(PyMove)
+            .collect()
+    }
+
+    /// Get the status of the board.
+    /// The status is cached until the board changes, so repeated calls are cheap.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1")
+    /// >>> all(board.get_status() == rust_chess.BoardStatus.ONGOING for _ in range(1000))
+    /// True
+    /// >>> board.push(rust_chess.Move("a1a8"))
+    /// >>> board.get_status() == rust_chess.BoardStatus.CHECKMATE
+    /// True
+    /// >>> _ = board.pop()
+    /// >>> board.get_status() == rust_chess.BoardStatus.ONGOING
+    /// True
+    /// ```
+    #[inline]
+    fn get_status(&self) -> PyBoardStatus {
+        *self.status.get_or_init(|| match self.board.status() {
+            chess::BoardStatus::Checkmate => PyBoardStatus::Checkmate,
+            chess::BoardStatus::Stalemate => PyBoardStatus::Stalemate,
+            chess::BoardStatus::Ongoing => {
+                // The game is ongoing, so only the halfmove clock needs checking for the seventy-five-move rule
+                if self.is_insufficient_material() {
+                    PyBoardStatus::InsufficientMaterial
+                } else if self.halfmove_clock >= 150 {
+                    PyBoardStatus::SeventyFiveMoves
+                } else if self.is_fivefold_repetition() {
+                    PyBoardStatus::FiveFoldRepetition
+                } else {
+                    PyBoardStatus::Ongoing
+                }
+            }
+        })
+    }
+
+    /// Get the outcome of the game, otherwise None if the game is ongoing.
+    /// If `claim_draw` is True, a claimable fifty-move or threefold repetition draw also ends the game.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().outcome()
+    ///
+    /// >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").outcome()
+    /// Outcome(STALEMATE, None)
+    /// >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").outcome(claim_draw=True)
+    /// Outcome(FIFTY_MOVES, None)
+    /// ```
+    #[inline]
+    #[pyo3(signature = (claim_draw = false))]
+    fn outcome(&self, claim_draw: bool) -> Option<PyOutcome> {
+        let termination = match self.get_status() {
+            PyBoardStatus::Checkmate => {
+                // The side to move is checkmated
+                return Some(PyOutcome {
+                    termination: PyTermination::Checkmate,
+                    winner: Some(PyColor(!self.board.side_to_move())),
+                });
+            }
+            PyBoardStatus::Stalemate => PyTermination::Stalemate,
+            PyBoardStatus::InsufficientMaterial => PyTermination::InsufficientMaterial,
+            PyBoardStatus::SeventyFiveMoves => PyTermination::SeventyFiveMoves,
+            PyBoardStatus::FiveFoldRepetition => PyTermination::FiveFoldRepetition,
+            PyBoardStatus::Ongoing => {
+                if claim_draw && self.can_claim_fifty_moves() {
+                    PyTermination::FiftyMoves
+                } else if claim_draw && self.can_claim_threefold_repetition() {
+                    PyTermination::ThreeFoldRepetition
+                } else {
+                    return None;
+                }
+            }
         };
 
-        // Increment fullmove number if black moves
-        if self.board.side_to_move() == chess::Color::Black {
-            self.fullmove_number += 1;
+        Some(PyOutcome {
+            termination,
+            winner: None,
+        })
+    }
+
+    /// Get the result of the game: "1-0", "0-1", "1/2-1/2", or None if the game is ongoing.
+    /// If `claim_draw` is True, a claimable fifty-move or threefold repetition draw also counts as a draw.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board().result()
+    ///
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").result()
+    /// '0-1'
+    /// >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").result()
+    /// '1/2-1/2'
+    /// >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60").result(claim_draw=True)
+    /// '1/2-1/2'
+    /// ```
+    #[inline]
+    #[pyo3(signature = (claim_draw = false))]
+    fn result(&self, claim_draw: bool) -> Option<&str> {
+        self.outcome(claim_draw).map(|outcome| outcome.result())
+    }
+
+    /// Checks if the game is over (checkmate, stalemate, insufficient material, seventy-five-move rule, or fivefold repetition).
+    /// If `claim_draw` is True, a claimable fifty-move or threefold repetition draw also ends the game.
+    ///
+    /// ```python
+    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_game_over()
+    /// True
+    /// >>> rust_chess.Board("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").is_game_over()
+    /// True
+    /// >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 100 60")
+    /// >>> board.is_game_over(), board.is_game_over(claim_draw=True)
+    /// (False, True)
+    /// >>> rust_chess.Board().is_game_over(claim_draw=True)
+    /// False
+    /// ```
+    #[inline]
+    #[pyo3(signature = (claim_draw = false))]
+    fn is_game_over(&self, claim_draw: bool) -> bool {
+        self.outcome(claim_draw).is_some()
+    }
+}
+
+/// Parse a FEN string into a chess crate board, the halfmove clock, and the fullmove number.
+/// Raises a `ValueError` naming the problem if the FEN string is invalid.
+///
+/// If `chess960` is True, the castling rights are parsed as Chess960 castling rooks instead, and the chess crate board has none.
+///
+#[inline]
+fn parse_fen(fen: &str, chess960: bool) -> PyResult<(chess::Board, u8, u16, chess::BitBoard)> {
+    // The chess crate only supports standard castling, so parse Chess960 castling rights separately
+    let mut chess960_castling = None;
+    let mut fen = fen.to_string();
+    if chess960 {
+        let mut parts: Vec<&str> = fen.split_whitespace().collect();
+        if parts.len() == 6 {
+            chess960_castling = Some(parts[2].to_string());
+            parts[2] = "-";
+            fen = parts.join(" ");
+        }
+    }
+    let fen = fen.as_str();
+
+    PyBoard::validate_fen(fen)?;
+
+    // Extract the halfmove clock and fullmove number from the FEN string
+    let mut parts: Vec<&str> = fen.split_whitespace().collect();
+
+    // Normalize the side to move, since the chess crate only accepts lowercase
+    let side_to_move = parts[1].to_lowercase();
+    parts[1] = side_to_move.as_str();
+    let fen = parts.join(" ");
+
+    // Parse the halfmove clock and fullmove number
+    let halfmove_clock = parts[4]
+        .parse::<u8>()
+        .map_err(|_| PyValueError::new_err(format!("Invalid FEN: invalid halfmove clock '{}'", parts[4])))?;
+    let fullmove_number = parts[5]
+        .parse::<u16>()
+        .map_err(|_| PyValueError::new_err(format!("Invalid FEN: invalid fullmove number '{}'", parts[5])))?;
+
+    // Parse the board using the chess crate
+    let board = chess::Board::from_str(&fen)
+        .map_err(|e| PyValueError::new_err(format!("Invalid FEN: {e}")))?;
+
+    let castling_rooks = match chess960_castling {
+        Some(castling) => parse_chess960_castling(&board, &castling)?,
+        None => chess::EMPTY,
+    };
+
+    Ok((board, halfmove_clock, fullmove_number, castling_rooks))
+}
+
+/// Parse Chess960 castling rights in X-FEN or Shredder-FEN (e.g. `KQkq` or `HAha`) into the bitboard of the castling rooks.
+///
+#[inline]
+fn parse_chess960_castling(board: &chess::Board, castling: &str) -> PyResult<chess::BitBoard> {
+    let invalid = || PyValueError::new_err(format!("Invalid FEN: invalid castling rights '{castling}'"));
+
+    let mut castling_rooks = chess::EMPTY;
+    if castling == "-" {
+        return Ok(castling_rooks);
+    }
+
+    for c in castling.chars() {
+        let color = if c.is_ascii_uppercase() { chess::Color::White } else { chess::Color::Black };
+        let back_rank = chess::get_rank(color.to_my_backrank());
+        let king_square = board.king_square(color);
+        if chess::BitBoard::from_square(king_square) & back_rank == chess::EMPTY {
+            return Err(invalid());
         }
 
-        // Update the current board
-        self.board = temp_board;
+        // The rooks of the color on their back rank, from the a-file to the h-file
+        let mut rooks = board.pieces(chess::Piece::Rook) & board.color_combined(color) & back_rank;
+        let king_file = king_square.get_file();
+        let rook = match c.to_ascii_lowercase() {
+            'k' => rooks.filter(|rook| rook.get_file() > king_file).last(),
+            'q' => rooks.find(|rook| rook.get_file() < king_file),
+            file @ 'a'..='h' => rooks.find(|rook| rook.to_string().starts_with(file)),
+            _ => None,
+        };
+        castling_rooks |= chess::BitBoard::from_square(rook.ok_or_else(invalid)?);
+    }
 
-        // We can assume the GIL is acquired, since this function is only called from Python
-        let py = unsafe { Python::assume_gil_acquired() };
+    Ok(castling_rooks)
+}
 
-        // Create a new move generator using the chess crate
-        self.move_gen = Py::new(py, PyMoveGenerator(chess::MoveGen::new_legal(&temp_board)))?;
+/// Get the X-FEN castling rights of Chess960 castling rooks (e.g. `KQkq`).
+/// The outermost rook on each side of the king is written as "K" or "Q", and any other rook as its file.
+///
+#[inline]
+fn format_chess960_castling(board: &chess::Board, castling_rooks: chess::BitBoard) -> String {
+    let mut castling = String::new();
+
+    for color in chess::ALL_COLORS {
+        let back_rank = chess::get_rank(color.to_my_backrank());
+        let rooks = board.pieces(chess::Piece::Rook) & board.color_combined(color) & back_rank;
+        let king_file = board.king_square(color).get_file();
+
+        // Kingside rooks first, from the h-file to the a-file
+        let mut color_castling_rooks: Vec<chess::Square> = (castling_rooks & back_rank & rooks).collect();
+        color_castling_rooks.reverse();
+        for rook in color_castling_rooks {
+            let file = rook.get_file();
+            let mut other_rooks = rooks;
+            let symbol = if file > king_file && other_rooks.all(|other| other.get_file() <= file) {
+                'K'
+            } else if file < king_file && other_rooks.all(|other| other.get_file() >= file) {
+                'Q'
+            } else {
+                rook.to_string().to_ascii_uppercase().chars().next().unwrap_or('-')
+            };
+
+            castling.push(if color == chess::Color::White { symbol } else { symbol.to_ascii_lowercase() });
+        }
+    }
 
-        Ok(())
+    if castling.is_empty() {
+        castling.push('-');
     }
+    castling
+}
 
-    /// Get the bitboard of the side to move's pinned pieces
-    #[inline]
-    fn get_pinned_bitboard(&self) -> PyBitboard {
-        PyBitboard(*self.board.pinned())
+/// Get the FEN string of a chess crate board with the given move counters.
+///
+#[inline]
+fn format_fen(board: &chess::Board, halfmove_clock: u8, fullmove_number: u16) -> String {
+    let base_fen = board.to_string();
+
+    // 0: board, 1: player, 2: castling, 3: en passant, 4: halfmove clock, 5: fullmove number
+    let mut parts: Vec<&str> = base_fen.split_whitespace().collect();
+
+    // The chess crate does not track the halfmove clock and fullmove number correctly, so we need to add them manually.
+    let halfmove_clock_str: String = halfmove_clock.to_string();
+    let fullmove_number_str: String = fullmove_number.to_string();
+    parts[4] = halfmove_clock_str.as_str();
+    parts[5] = fullmove_number_str.as_str();
+
+    // The chess crate writes the square of the pawn that can be captured, so use the square behind it instead
+    let en_passant_str = board
+        .en_passant()
+        .and_then(|square| en_passant_target(square, board.side_to_move()))
+        .map(|square| square.to_string());
+    if let Some(en_passant_str) = &en_passant_str {
+        parts[3] = en_passant_str.as_str();
     }
 
-    /// Get the bitboard of the pieces putting the side to move in check
-    #[inline]
-    fn get_checkers_bitboard(&self) -> PyBitboard {
-        PyBitboard(*self.board.checkers())
+    parts.join(" ")
+}
+
+/// Get the en passant target square (e.g. e3) written in FEN strings
+/// from the square of the pawn that can be captured (e.g. e4) and the side to move.
+///
+#[inline]
+pub(crate) fn en_passant_target(pawn_square: chess::Square, side_to_move: chess::Color) -> Option<chess::Square> {
+    match side_to_move {
+        chess::Color::White => pawn_square.up(),
+        chess::Color::Black => pawn_square.down(),
     }
+}
 
-    /// Get the bitboard of all the pieces
-    #[inline]
-    fn get_all_bitboard(&self) -> PyBitboard {
-        PyBitboard(*self.board.combined())
+/// Get the Standard Algebraic Notation (SAN) of a move on a chess crate board (see `Board.san`).
+/// Raises a `ValueError` if there is no piece on the source square.
+///
+#[inline]
+fn format_san(board: &chess::Board, chess_move: chess::ChessMove, check_suffix: bool) -> PyResult<String> {
+    let source = chess_move.get_source();
+    let dest = chess_move.get_dest();
+
+    let piece = board
+        .piece_on(source)
+        .ok_or_else(|| PyValueError::new_err("No piece on the source square"))?;
+
+    // A piece on the destination square, or en passant (a pawn changing files onto an empty square)
+    let is_capture =
+        board.piece_on(dest).is_some() || (piece == chess::Piece::Pawn && source.get_file() != dest.get_file());
+
+    let mut san = String::new();
+
+    // Castling: the king moves two files
+    if piece == chess::Piece::King && source.get_file().to_index().abs_diff(dest.get_file().to_index()) == 2 {
+        san.push_str(if dest.get_file() == chess::File::G { "O-O" } else { "O-O-O" });
+    } else {
+        if piece == chess::Piece::Pawn {
+            // Pawn captures start with the source file
+            if is_capture {
+                san.push_str(&source.to_string()[..1]);
+            }
+        } else {
+            san.push_str(&piece.to_string(chess::Color::White));
+
+            // Disambiguate from other pieces of the same type that can move to the destination
+            let others: Vec<chess::Square> = chess::MoveGen::new_legal(board)
+                .filter(|other| {
+                    other.get_dest() == dest
+                        && other.get_source() != source
+                        && board.piece_on(other.get_source()) == Some(piece)
+                })
+                .map(|other| other.get_source())
+                .collect();
+            if !others.is_empty() {
+                let source_name = source.to_string();
+                if others.iter().all(|other| other.get_file() != source.get_file()) {
+                    san.push_str(&source_name[..1]);
+                } else if others.iter().all(|other| other.get_rank() != source.get_rank()) {
+                    san.push_str(&source_name[1..]);
+                } else {
+                    san.push_str(&source_name);
+                }
+            }
+        }
+
+        if is_capture {
+            san.push('x');
+        }
+        san.push_str(&dest.to_string());
+
+        if let Some(promotion) = chess_move.get_promotion() {
+            san.push('=');
+            san.push_str(&promotion.to_string(chess::Color::White));
+        }
     }
 
-    /// Get the bitboard of all the pieces of a certain color
-    #[inline]
-    fn get_color_bitboard(&self, color: PyColor) -> PyBitboard {
-        PyBitboard(*self.board.color_combined(color.0))
+    // Check and checkmate suffixes
+    if check_suffix {
+        let new_board = board.make_move_new(chess_move);
+        if new_board.status() == chess::BoardStatus::Checkmate {
+            san.push('#');
+        } else if *new_board.checkers() != chess::EMPTY {
+            san.push('+');
+        }
     }
 
-    /// Get the bitboard of all the pieces of a certain type
-    #[inline]
-    fn get_piece_type_bitboard(&self, piece_type: PyPieceType) -> PyBitboard {
-        PyBitboard(*self.board.pieces(piece_type.0))
+    Ok(san)
+}
+
+/// Parse EPD operations (e.g. `bm Qg6; id "WAC.001";`) into a map from opcode to operands.
+/// Semicolons inside quoted operands don't end the operation.
+///
+#[inline]
+fn parse_epd_operations(operations_str: &str) -> HashMap<String, String> {
+    let mut operations = HashMap::new();
+    let mut operation = String::new();
+    let mut in_quotes = false;
+
+    for c in operations_str.chars().chain(std::iter::once(';')) {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                operation.push(c);
+            }
+            ';' if !in_quotes => {
+                let trimmed = operation.trim();
+                if !trimmed.is_empty() {
+                    let (opcode, operands) = trimmed.split_once(char::is_whitespace).unwrap_or((trimmed, ""));
+                    let operands = operands.trim();
+                    let operands = operands
+                        .strip_prefix('"')
+                        .and_then(|operands| operands.strip_suffix('"'))
+                        .unwrap_or(operands);
+                    operations.insert(opcode.to_string(), operands.to_string());
+                }
+                operation.clear();
+            }
+            _ => operation.push(c),
+        }
     }
 
-    /// Get the bitboard of all the pieces of a certain color and type
-    #[inline]
-    fn get_piece_bitboard(&self, piece: PyPiece) -> PyBitboard {
-        PyBitboard(self.board.pieces(piece.piece_type.0) & self.board.color_combined(piece.color.0))
+    operations
+}
+
+/// Split PGN movetext into tokens, leaving out comments and variations.
+///
+#[inline]
+fn pgn_movetext_tokens(movetext: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut variation_depth: usize = 0;
+    let mut chars = movetext.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '{' || c == ';' || c == '(' || c == ')' || c.is_whitespace() {
+            if variation_depth == 0 && !token.is_empty() {
+                tokens.push(std::mem::take(&mut token));
+            }
+            token.clear();
+        }
+
+        match c {
+            '{' => while chars.next().is_some_and(|c| c != '}') {}, // Brace comment
+            ';' => while chars.next().is_some_and(|c| c != '\n') {}, // Rest of line comment
+            '(' => variation_depth += 1,
+            ')' => variation_depth = variation_depth.saturating_sub(1),
+            _ if c.is_whitespace() => {}
+            _ => token.push(c),
+        }
+    }
+    if variation_depth == 0 && !token.is_empty() {
+        tokens.push(token);
     }
 
-    // TODO: set_iterator_mask, will have to implement PyBitboard
-    // TODO: remove_mask
-
-    // Fixme
-    // /// Get the number of moves remaining in the move generator.
-    // /// This is the number of remaining moves that can be generated.
-    // /// The default mask is all legal moves.
-    // ///
-    // #[inline]
-    // fn get_moves_remaining(&self) -> usize {
-    //     // We can assume the GIL is acquired, since this function is only called from Python
-    //     let py = unsafe { Python::assume_gil_acquired() };
-    //
-    //     // Get the length of the move generator
-    //     self.move_gen.borrow(py).0.len()
-    // }
+    tokens
+}
 
-    /// Remove a move from the move generator.
-    /// Prevents the move from being generated.
-    /// Useful if you already have a certain move and don't need to generate it again.
-    ///
-    #[inline]
-    fn remove_move(&mut self, chess_move: PyMove) {
-        // We can assume the GIL is acquired, since this function is only called from Python
-        let py = unsafe { Python::assume_gil_acquired() };
+/// Get a PGN header line (e.g. `[Event "?"]`), escaping backslashes and quotes in the value.
+///
+#[inline]
+fn format_pgn_header(key: &str, value: &str) -> String {
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("[{key} \"{value}\"]\n")
+}
 
-        // Remove the move from the generator
-        self.move_gen.borrow_mut(py).0.remove_move(chess_move.0);
+/// Count the leaf nodes of the legal move tree to a depth.
+fn perft(board: &chess::Board, depth: u32) -> u64 {
+    let move_gen = chess::MoveGen::new_legal(board);
+
+    match depth {
+        0 => 1,
+        // Count the leaf moves directly instead of making them
+        1 => move_gen.len() as u64,
+        _ => move_gen
+            .map(|chess_move| perft(&board.make_move_new(chess_move), depth - 1))
+            .sum(),
     }
+}
 
-    /// Reset the move generator for the current board
+// Rust-only helper methods
+impl PyBoard {
+    /// Create a new board from a chess crate board with an empty move stack.
+    ///
     #[inline]
-    fn reset_move_generator(&mut self) -> PyResult<()> {
+    pub(crate) fn from_board(board: chess::Board, halfmove_clock: u8, fullmove_number: u16) -> PyResult<Self> {
         // We can assume the GIL is acquired, since this function is only called from Python
         let py = unsafe { Python::assume_gil_acquired() };
 
         // Create a new move generator using the chess crate
-        self.move_gen = Py::new(py, PyMoveGenerator(chess::MoveGen::new_legal(&self.board)))?;
+        let move_gen = Py::new(py, PyMoveGenerator::new(&board))?;
 
-        Ok(())
+        Ok(PyBoard {
+            board,
+            move_gen,
+            halfmove_clock,
+            fullmove_number,
+            chess960: false,
+            castling_rooks: chess::EMPTY,
+            move_stack: Vec::new(),
+            status: OnceLock::new(),
+        })
     }
 
-    /// Get the next remaining move of the generator.
-    /// Updates the move generator to the next move.
-    /// Unless the mask is set, this will return the next legal move by default.
+    /// Get the FEN string of a chess crate board with the given move counters and Chess960 castling rooks.
+    /// In Chess960 mode, the castling rights are written as X-FEN from the castling rooks.
     ///
     #[inline]
-    fn next_move(&mut self) -> Option<PyMove> {
-        // We can assume the GIL is acquired, since this function is only called from Python
-        let py = unsafe { Python::assume_gil_acquired() };
+    fn format_fen_with_castling(
+        &self,
+        board: &chess::Board,
+        halfmove_clock: u8,
+        fullmove_number: u16,
+        castling_rooks: chess::BitBoard,
+    ) -> String {
+        let fen = format_fen(board, halfmove_clock, fullmove_number);
+        if !self.chess960 {
+            return fen;
+        }
 
-        // Get the next move from the generator
-        self.move_gen.borrow_mut(py).__next__()
+        let castling = format_chess960_castling(board, castling_rooks);
+        let mut parts: Vec<&str> = fen.split_whitespace().collect();
+        parts[2] = castling.as_str();
+        parts.join(" ")
     }
 
-    /// Generate the next remaining legal moves for the current board.
-    /// Exhausts the move generator if fully iterated over.
-    /// Updates the move generator.
+    /// Get the FEN string of the position before the first move on the move stack.
     ///
     #[inline]
-    fn generate_legal_moves(&mut self) -> Py<PyMoveGenerator> {
-        // We can assume the GIL is acquired, since this function is only called from Python
-        let py = unsafe { Python::assume_gil_acquired() };
-
-        // Set the iterator mask to everything (check all legal moves)
-        self.move_gen
-            .borrow_mut(py)
-            .0
-            .set_iterator_mask(!chess::EMPTY);
-
-        // Share ownership with Python
-        self.move_gen.clone_ref(py)
+    fn get_root_fen(&self) -> String {
+        match self.move_stack.first() {
+            Some(state) => {
+                self.format_fen_with_castling(&state.board, state.halfmove_clock, state.fullmove_number, state.castling_rooks)
+            }
+            None => self.get_fen(),
+        }
     }
 
-    #[inline]
-    /// Generate the next remaining legal captures for the current board.
-    /// Exhausts the move generator if fully iterated over.
-    /// Updates the move generator.
+    /// Get the bitboard of the Chess960 castling rooks of a color.
     ///
-    fn generate_legal_captures(&mut self) -> Py<PyMoveGenerator> {
-        // Get the mask of enemy‐occupied squares
-        let targets_mask = self.board.color_combined(!self.board.side_to_move());
-
-        // We can assume the GIL is acquired, since this function is only called from Python
-        let py = unsafe { Python::assume_gil_acquired() };
-
-        // Set the iterator mask to the targets mask (check all legal captures [moves onto enemy pieces])
-        self.move_gen
-            .borrow_mut(py)
-            .0
-            .set_iterator_mask(*targets_mask);
-
-        // Share ownership with Python
-        self.move_gen.clone_ref(py)
+    #[inline]
+    fn get_chess960_castling_rooks(&self, color: chess::Color) -> chess::BitBoard {
+        self.castling_rooks & self.board.color_combined(color)
     }
 
-    /// Checks if the side to move has insufficient material to checkmate the opponent.
-    /// The cases where this is true are:
-    ///     1. K vs K
-    ///     2. K vs K + N
-    ///     3. K vs K + B
-    ///     4. K + B vs K + B with the bishops on the same color.
+    /// Get the Chess960 castling rooks after a move.
+    /// Moving the king loses both castling rights, and moving or capturing a rook loses its castling right.
     ///
-    /// ```python
-    /// >>> rust_chess.Board().is_insufficient_material()
-    /// False
-    /// >>> rust_chess.Board("4k3/8/8/8/8/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K
-    /// True
-    /// >>> rust_chess.Board("4k3/8/8/8/5N2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + N
-    /// True
-    /// >>> rust_chess.Board("4k3/8/8/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K vs K + B
-    /// True
-    /// >>> rust_chess.Board("4k3/8/8/5b2/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B different color
-    /// False
-    /// >>> rust_chess.Board("4k3/8/5b2/8/5B2/8/8/4K3 w - - 0 1").is_insufficient_material() # K + B vs K + B same color
-    /// True
-    /// ```
     #[inline]
-    fn is_insufficient_material(&self) -> bool {
-        let kings = self.board.pieces(chess::Piece::King);
-
-        // Get the bitboards of the white and black pieces without the kings
-        let white_bb = self.board.color_combined(chess::Color::White) & !kings;
-        let black_bb = self.board.color_combined(chess::Color::Black) & !kings;
-        let combined_bb = white_bb | black_bb;
-
-        // King vs King: Combined bitboard minus kings is empty
-        if combined_bb == chess::EMPTY {
-            return true;
+    fn get_castling_rooks_after(&self, chess_move: PyMove) -> chess::BitBoard {
+        let source = chess_move.0.get_source();
+        let mut castling_rooks = self.castling_rooks
+            & !chess::BitBoard::from_square(source)
+            & !chess::BitBoard::from_square(chess_move.0.get_dest());
+
+        if self.board.piece_on(source) == Some(chess::Piece::King) {
+            castling_rooks &= !chess::get_rank(self.board.side_to_move().to_my_backrank());
         }
 
-        let remaining_num_pieces = combined_bb.popcnt();
+        castling_rooks
+    }
 
-        if remaining_num_pieces <= 2 {
-            let knights = self.board.pieces(chess::Piece::Knight);
-            let bishops = self.board.pieces(chess::Piece::Bishop);
+    /// Make a move onto a new chess crate board without checking legality.
+    /// Returns `None` if the side to move has no piece on the source square, since the chess crate panics on those moves.
+    ///
+    #[inline]
+    fn try_make_move_new(&self, chess_move: PyMove) -> Option<chess::Board> {
+        (self.board.color_on(chess_move.0.get_source()) == Some(self.board.side_to_move()))
+            .then(|| self.board.make_move_new(chess_move.0))
+    }
 
-            // King vs King + Knight/Bishop: Combined bitboard minus kings and knight/bishop is empty
-            if remaining_num_pieces == 1 && combined_bb & !(knights | bishops) == chess::EMPTY {
-                return true;
-            } else if *knights == chess::EMPTY {
-                // Only bishops left
-                let white_bishops = bishops & white_bb;
-                let black_bishops = bishops & black_bb;
+    /// Get the legal move matching a SAN string (e.g. "Nf3").
+    /// Check, checkmate, and annotation suffixes are ignored, and "0-0" is accepted for castling.
+    ///
+    #[inline]
+    pub(crate) fn parse_san(&self, san: &str) -> PyResult<PyMove> {
+        // Zeros only appear in SAN when castling with zeros instead of the letter O
+        let normalized = san.trim_end_matches(['+', '#', '!', '?']).replace('0', "O");
 
-                if white_bishops != chess::EMPTY && black_bishops != chess::EMPTY // Both sides have a bishop
-                    // King + Bishop vs King + Bishop same color: White and black bishops are on the same color square
-                    && PySquare(white_bishops.to_square()).get_color() == PySquare(black_bishops.to_square()).get_color()
-                {
-                    return true;
-                }
-            }
+        let mut matches = chess::MoveGen::new_legal(&self.board)
+            .map(PyMove)
+            .filter(|&chess_move| self.san(chess_move, false).is_ok_and(|move_san| move_san == normalized));
+
+        match (matches.next(), matches.next()) {
+            (Some(chess_move), None) => Ok(chess_move),
+            _ => Err(PyValueError::new_err(format!("Invalid or illegal SAN move: {san}"))),
         }
-        false
     }
 
-    /// Checks if the halfmoves since the last pawn move or capture is >= 100
-    /// and the game is ongoing (not checkmate or stalemate).
+    /// Get a move with its move number for the side to move (e.g. "3. Nf3" or "3... Nf6").
     ///
-    /// ```python
-    /// >>> rust_chess.Board().is_fifty_moves
-    /// False
-    /// >>> rust_chess.Board("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 50 1").is_fifty_moves()
-    /// True
-    /// ```
     #[inline]
-    fn is_fifty_moves(&self) -> bool {
-        self.halfmove_clock >= 100 && self.board.status() == chess::BoardStatus::Ongoing
+    fn format_move_number(&self, move_text: &str) -> String {
+        if self.board.side_to_move() == chess::Color::White {
+            format!("{}. {move_text}", self.fullmove_number)
+        } else {
+            format!("{}... {move_text}", self.fullmove_number)
+        }
     }
 
-    /// Checks if the halfmoves since the last pawn move or capture is >= 150
-    /// and the game is ongoing (not checkmate or stalemate).
+    /// Set the board to a chess crate board with the given move counters, clearing the move stack.
+    /// Replaces the contents of the move generator in place, so it isn't reallocated.
     ///
     #[inline]
-    fn is_seventy_five_moves(&self) -> bool {
-        self.halfmove_clock >= 150 && self.board.status() == chess::BoardStatus::Ongoing
-    }
+    fn set_board(&mut self, board: chess::Board, halfmove_clock: u8, fullmove_number: u16) -> PyResult<()> {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        *self.move_gen.try_borrow_mut(py)? = PyMoveGenerator::new(&board);
+
+        self.board = board;
+        self.halfmove_clock = halfmove_clock;
+        self.fullmove_number = fullmove_number;
+        self.move_stack.clear();
+        self.status = OnceLock::new();
 
-    // TODO: Check threefold and fivefold repetition
+        Ok(())
+    }
 
-    /// Checks if the game is in a fivefold repetition.
-    /// TODO: Currently not implementable due to no storage of past moves
+    /// Get the current state of the board with the move about to be made from it.
+    ///
     #[inline]
-    fn is_fivefold_repetition(&self) -> bool {
-        false
+    fn get_state(&self, chess_move: PyMove) -> BoardState {
+        BoardState {
+            board: self.board,
+            chess_move,
+            castling_rooks: self.castling_rooks,
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+        }
     }
 
-    /// Checks if the side to move is in check.
+    /// Check if the side to move has legal moves (the game isn't over by checkmate or stalemate).
     ///
-    /// ```python
-    /// >>> rust_chess.Board().is_check
-    /// False
-    /// >>> rust_chess.Board("rnb1kbnr/pppp1ppp/4p3/8/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").is_check()
-    /// True
-    /// ```
     #[inline]
-    fn is_check(&self) -> bool {
-        *self.board.checkers() != chess::EMPTY
+    fn has_legal_moves(&self) -> bool {
+        !matches!(self.get_status(), PyBoardStatus::Checkmate | PyBoardStatus::Stalemate)
     }
 
-    /// Checks if the side to move is in stalemate
+    /// Count the number of times the current position has occurred (including the current position).
+    /// Only looks back as far as the halfmove clock allows.
+    ///
     #[inline]
-    fn is_stalemate(&self) -> bool {
-        self.board.status() == chess::BoardStatus::Stalemate
+    fn get_repetition_count(&self) -> usize {
+        // Positions with the same side to move are every other halfmove
+        1 + self
+            .move_stack
+            .iter()
+            .rev()
+            .take(self.halfmove_clock as usize)
+            .skip(1)
+            .step_by(2)
+            .filter(|state| state.board == self.board)
+            .count()
     }
 
-    /// Checks if the side to move is in checkmate
+    /// Get the bitboard of the pieces of a color in an occupancy attacking a square.
+    /// Sliding pieces are blocked by the pieces in the occupancy, which allows x-rays when pieces are removed from it.
+    ///
     #[inline]
-    fn is_checkmate(&self) -> bool {
-        self.board.status() == chess::BoardStatus::Checkmate
+    fn get_attackers(&self, color: chess::Color, square: chess::Square, occupied: chess::BitBoard) -> chess::BitBoard {
+        let queens = self.board.pieces(chess::Piece::Queen);
+        let diagonal_sliders = self.board.pieces(chess::Piece::Bishop) | queens;
+        let straight_sliders = self.board.pieces(chess::Piece::Rook) | queens;
+        let pawns = self.board.pieces(chess::Piece::Pawn) & self.board.color_combined(color);
+
+        // Pieces attack the square if the same piece on the square would attack them
+        let attackers = chess::get_pawn_attacks(square, !color, pawns)
+            | (chess::get_knight_moves(square) & self.board.pieces(chess::Piece::Knight))
+            | (chess::get_king_moves(square) & self.board.pieces(chess::Piece::King))
+            | (chess::get_bishop_moves(square, occupied) & diagonal_sliders)
+            | (chess::get_rook_moves(square, occupied) & straight_sliders);
+
+        attackers & self.board.color_combined(color) & occupied
     }
 
-    /// Get the status of the board
+    /// Get the piece type a move would capture, otherwise None.
+    /// Handles en passant, where the captured pawn is not on the destination square.
+    ///
     #[inline]
-    fn get_status(&self) -> PyBoardStatus {
-        let status = self.board.status();
-        match status {
-            chess::BoardStatus::Checkmate => PyBoardStatus::Checkmate,
-            chess::BoardStatus::Stalemate => PyBoardStatus::Stalemate,
-            chess::BoardStatus::Ongoing => {
-                if self.is_insufficient_material() {
-                    PyBoardStatus::InsufficientMaterial
-                } else if self.is_seventy_five_moves() {
-                    PyBoardStatus::SeventyFiveMoves
-                } else if self.is_fivefold_repetition() {
-                    PyBoardStatus::FiveFoldRepetition
-                } else {
-                    PyBoardStatus::Ongoing
-                }
-            }
-        }
+    fn get_captured_piece_type(&self, chess_move: PyMove) -> Option<chess::Piece> {
+        self.captured_square(chess_move)
+            .and_then(|square| self.board.piece_on(square.0))
     }
 }
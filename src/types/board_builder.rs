@@ -0,0 +1,223 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+use crate::types::{board::{PyBoard, en_passant_target}, color::PyColor, piece::PyPiece, square::PySquare};
+
+/// Board builder class.
+/// Builds a board piece by piece instead of from a FEN string.
+/// Starts empty with white to move, no castling rights, and no en passant square.
+/// The setters return the builder, so they can be chained.
+///
+/// ```python
+/// >>> builder = rust_chess.BoardBuilder()
+/// >>> builder.set_piece(rust_chess.E1, rust_chess.Piece(rust_chess.KING, rust_chess.WHITE))
+/// BoardBuilder(8/8/8/8/8/8/8/4K3 w - - 0 1)
+/// >>> builder.set_piece(rust_chess.D1, rust_chess.Piece(rust_chess.QUEEN, rust_chess.WHITE)).set_piece(
+/// ...     rust_chess.E8, rust_chess.Piece(rust_chess.KING, rust_chess.BLACK)
+/// ... ).build()
+/// 4k3/8/8/8/8/8/8/3QK3 w - - 0 1
+/// ```
+#[gen_stub_pyclass]
+#[pyclass(name = "BoardBuilder")]
+#[derive(Clone, Copy)]
+pub(crate) struct PyBoardBuilder(chess::BoardBuilder);
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyBoardBuilder {
+    /// Create a new empty board builder.
+    ///
+    /// ```python
+    /// >>> rust_chess.BoardBuilder()
+    /// BoardBuilder(8/8/8/8/8/8/8/8 w - - 0 1)
+    /// ```
+    #[new]
+    #[inline]
+    fn new() -> Self {
+        PyBoardBuilder(chess::BoardBuilder::new())
+    }
+
+    /// Put a piece on a square, replacing any piece already on it.
+    /// Does not update the castling rights.
+    ///
+    #[inline]
+    fn set_piece(mut slf: PyRefMut<'_, Self>, square: PySquare, piece: PyPiece) -> PyRefMut<'_, Self> {
+        slf.0.piece(square.0, piece.piece_type.0, piece.color.0);
+        slf
+    }
+
+    /// Remove any piece from a square.
+    /// Does not update the castling rights.
+    ///
+    /// ```python
+    /// >>> rust_chess.BoardBuilder().set_piece(rust_chess.A1, rust_chess.Piece(rust_chess.ROOK, rust_chess.WHITE)).clear_square(rust_chess.A1)
+    /// BoardBuilder(8/8/8/8/8/8/8/8 w - - 0 1)
+    /// ```
+    #[inline]
+    fn clear_square(mut slf: PyRefMut<'_, Self>, square: PySquare) -> PyRefMut<'_, Self> {
+        slf.0.clear_square(square.0);
+        slf
+    }
+
+    /// Set the side to move.
+    ///
+    /// ```python
+    /// >>> rust_chess.BoardBuilder().set_turn(rust_chess.BLACK)
+    /// BoardBuilder(8/8/8/8/8/8/8/8 b - - 0 1)
+    /// ```
+    #[inline]
+    fn set_turn(mut slf: PyRefMut<'_, Self>, color: PyColor) -> PyRefMut<'_, Self> {
+        slf.0.side_to_move(color.0);
+        slf
+    }
+
+    /// Set the castling rights of a color.
+    /// The king and rooks must be on their starting squares for the board to build.
+    ///
+    /// ```python
+    /// >>> rust_chess.BoardBuilder().set_castling_rights(rust_chess.WHITE, True, False)
+    /// BoardBuilder(8/8/8/8/8/8/8/8 w K - 0 1)
+    /// ```
+    #[inline]
+    fn set_castling_rights(
+        mut slf: PyRefMut<'_, Self>,
+        color: PyColor,
+        kingside: bool,
+        queenside: bool,
+    ) -> PyRefMut<'_, Self> {
+        let castle_rights = match (kingside, queenside) {
+            (true, true) => chess::CastleRights::Both,
+            (true, false) => chess::CastleRights::KingSide,
+            (false, true) => chess::CastleRights::QueenSide,
+            (false, false) => chess::CastleRights::NoRights,
+        };
+        slf.0.castle_rights(color.0, castle_rights);
+        slf
+    }
+
+    /// Set the en passant square, or clear it with None.
+    /// Only the file of the square is used, so either the target square (e.g. e3)
+    /// or the square of the pawn that can be captured (e.g. e4) can be given.
+    ///
+    /// ```python
+    /// >>> rust_chess.BoardBuilder().set_turn(rust_chess.BLACK).set_en_passant(rust_chess.E4)
+    /// BoardBuilder(8/8/8/8/8/8/8/8 b - e3 0 1)
+    /// ```
+    #[inline]
+    fn set_en_passant(mut slf: PyRefMut<'_, Self>, square: Option<PySquare>) -> PyRefMut<'_, Self> {
+        slf.0.en_passant(square.map(|square| square.0.get_file()));
+        slf
+    }
+
+    /// Build the board, with a halfmove clock of 0 and a fullmove number of 1.
+    /// Raises a `ValueError` naming the problem if the position is impossible
+    /// (e.g. not exactly one king per color, pawns on the first or last rank, or the side not to move in check).
+    ///
+    /// ```python
+    /// >>> builder = rust_chess.BoardBuilder().set_piece(rust_chess.E1, rust_chess.Piece(rust_chess.KING, rust_chess.WHITE))
+    /// >>> builder.build()
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid board: black must have exactly one king (found 0)
+    /// >>> builder.set_piece(rust_chess.E8, rust_chess.Piece(rust_chess.KING, rust_chess.BLACK)).set_piece(
+    /// ...     rust_chess.A8, rust_chess.Piece(rust_chess.KING, rust_chess.BLACK)
+    /// ... ).build()
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid board: black must have exactly one king (found 2)
+    /// >>> builder.set_piece(rust_chess.A8, rust_chess.Piece(rust_chess.ROOK, rust_chess.WHITE)).build()
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid board: the side not to move is in check
+    /// >>> builder.clear_square(rust_chess.A8).set_castling_rights(rust_chess.WHITE, True, False).build()
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid board: white castling rights need the king and rooks on their starting squares
+    /// >>> builder.set_castling_rights(rust_chess.WHITE, False, False).set_piece(
+    /// ...     rust_chess.D5, rust_chess.Piece(rust_chess.PAWN, rust_chess.WHITE)
+    /// ... ).set_en_passant(rust_chess.E6).build()
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid board: no pawn to capture en passant on e5
+    /// ```
+    #[inline]
+    fn build(&self) -> PyResult<PyBoard> {
+        // The chess crate allows pawns on the first and last ranks, so check them here
+        let mut back_ranks = chess::get_rank(chess::Rank::First) | chess::get_rank(chess::Rank::Eighth);
+        if back_ranks.any(|square| matches!(self.0[square], Some((chess::Piece::Pawn, _)))) {
+            return Err(PyValueError::new_err("Invalid board: pawns on the first or last rank"));
+        }
+
+        // Validate the rest of the position using the chess crate, which doesn't say what is wrong
+        let board = chess::Board::try_from(&self.0)
+            .map_err(|_| PyValueError::new_err(format!("Invalid board: {}", self.get_invalid_reason())))?;
+
+        PyBoard::from_board(board, 0, 1)
+    }
+
+    /// Get the representation of the builder as a FEN string (e.g. "BoardBuilder(8/8/8/8/8/8/8/4K3 w - - 0 1)").
+    /// The en passant square is written as the target square, like in `Board.get_fen`.
+    #[inline]
+    fn __repr__(&self) -> String {
+        let base_fen = self.0.to_string();
+
+        // 0: board, 1: player, 2: castling, 3: en passant, 4: halfmove clock, 5: fullmove number
+        let mut parts: Vec<&str> = base_fen.split_whitespace().collect();
+
+        // The chess crate writes the square of the pawn that can be captured, so use the square behind it instead
+        let en_passant_str = self
+            .0
+            .get_en_passant()
+            .and_then(|square| en_passant_target(square, self.0.get_side_to_move()))
+            .map(|square| square.to_string());
+        if let Some(en_passant_str) = &en_passant_str {
+            parts[3] = en_passant_str.as_str();
+        }
+
+        format!("BoardBuilder({})", parts.join(" "))
+    }
+}
+
+impl PyBoardBuilder {
+    /// Get the reason the chess crate can't build the board.
+    /// Checks the same conditions as the chess crate.
+    ///
+    #[inline]
+    fn get_invalid_reason(&self) -> String {
+        // Kings
+        for (color, name) in [(chess::Color::White, "white"), (chess::Color::Black, "black")] {
+            let count = chess::ALL_SQUARES
+                .iter()
+                .filter(|&&square| self.0[square] == Some((chess::Piece::King, color)))
+                .count();
+            if count != 1 {
+                return format!("{name} must have exactly one king (found {count})");
+            }
+        }
+
+        // En passant square (the square of the pawn that can be captured)
+        let side_to_move = self.0.get_side_to_move();
+        if let Some(square) = self.0.get_en_passant() {
+            if self.0[square] != Some((chess::Piece::Pawn, !side_to_move)) {
+                return format!("no pawn to capture en passant on {square}");
+            }
+        }
+
+        // Castling rights
+        for (color, name) in [(chess::Color::White, "white"), (chess::Color::Black, "black")] {
+            let castle_rights = self.0.get_castle_rights(color);
+            let king_square = chess::Square::make_square(color.to_my_backrank(), chess::File::E);
+            let rooks_unmoved = castle_rights
+                .unmoved_rooks(color)
+                .all(|square| self.0[square] == Some((chess::Piece::Rook, color)));
+            let king_unmoved = castle_rights == chess::CastleRights::NoRights
+                || self.0[king_square] == Some((chess::Piece::King, color));
+            if !rooks_unmoved || !king_unmoved {
+                return format!("{name} castling rights need the king and rooks on their starting squares");
+            }
+        }
+
+        // The only other invalid positions have the side not to move in check (including touching kings)
+        "the side not to move is in check".to_string()
+    }
+}
@@ -72,6 +72,21 @@ impl PyColor {
         *self == WHITE
     }
 
+    /// Get the index of the color as an integer (0 for white, 1 for black).
+    /// Useful for indexing tables by color.
+    ///
+    /// ```python
+    /// >>> int(rust_chess.WHITE)
+    /// 0
+    /// >>> int(rust_chess.BLACK)
+    /// 1
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn __int__(&self) -> u8 {
+        self.0.to_index() as u8
+    }
+
     /// Get the color as a boolean string.
     ///
     /// ```python
@@ -89,6 +104,20 @@ impl PyColor {
         }
     }
 
+    /// Get the opposite color.
+    /// Unlike `not color`, this returns a color instead of a boolean.
+    ///
+    /// ```python
+    /// >>> rust_chess.WHITE.opposite() == rust_chess.BLACK
+    /// True
+    /// >>> print(rust_chess.BLACK.opposite())
+    /// WHITE
+    /// ```
+    #[inline]
+    fn opposite(&self) -> PyColor {
+        PyColor(!self.0)
+    }
+
     /// Compare the color to another color or boolean.
     ///
     /// ```python
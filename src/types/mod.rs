@@ -4,3 +4,4 @@ pub(crate) mod bitboard;
 pub(crate) mod square;
 pub(crate) mod r#move;
 pub(crate) mod board;
+pub(crate) mod board_builder;
@@ -1,9 +1,25 @@
 use std::str::FromStr;
 
-use pyo3::{exceptions::PyValueError, prelude::*, types::PyAny};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyAny, PyTuple, PyType},
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
-use crate::types::{piece::PyPieceType, square::PySquare};
+use crate::types::{board::PyBoard, piece::PyPieceType, square::PySquare};
+
+// Directions (rank change, file change) of queen moves in the policy encoding: N, NE, E, SE, S, SW, W, NW
+const QUEEN_DIRECTIONS: [(i8, i8); 8] = [(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)];
+
+// Offsets (rank change, file change) of knight moves in the policy encoding, clockwise from north-northeast
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [(2, 1), (1, 2), (-1, 2), (-2, 1), (-2, -1), (-1, -2), (1, -2), (2, -1)];
+
+// Promotion pieces with their own planes in the policy encoding (queen promotions are queen moves)
+const UNDERPROMOTIONS: [chess::Piece; 3] = [chess::Piece::Knight, chess::Piece::Bishop, chess::Piece::Rook];
+
+// Number of move planes in the policy encoding (56 queen moves, 8 knight moves, and 9 underpromotions)
+const POLICY_PLANES: usize = 73;
 
 /// Move class.
 /// Represents a chess move.
@@ -29,7 +45,7 @@ use crate::types::{piece::PyPieceType, square::PySquare};
 /// True
 /// ```
 #[gen_stub_pyclass]
-#[pyclass(name = "Move", frozen, eq)]
+#[pyclass(name = "Move", module = "rust_chess", frozen, eq)]
 #[derive(Clone, Copy, Eq, PartialOrd, PartialEq, Default, Hash)]
 pub(crate) struct PyMove(pub(crate) chess::ChessMove);
 
@@ -70,7 +86,23 @@ impl PyMove {
         Err(PyValueError::new_err("Move must be a UCI string or a source and destination square with optional promotion piece type"))
     }
 
-    // TODO: from_san
+    /// Create a new move from a Standard Algebraic Notation (SAN) string (e.g. "Nf3") on a board.
+    /// Check, checkmate, and annotation suffixes (e.g. "+", "#", "!?") are ignored, and "0-0" is accepted for castling.
+    /// Raises a `ValueError` if the SAN string doesn't match exactly one legal move.
+    ///
+    /// ```python
+    /// >>> rust_chess.Move.from_san("Nf3", rust_chess.Board())
+    /// Move(g1, f3, None)
+    /// >>> rust_chess.Move.from_san("e5", rust_chess.Board())
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: Invalid or illegal SAN move: e5
+    /// ```
+    #[staticmethod]
+    #[inline]
+    pub(crate) fn from_san(san: &str, board: &PyBoard) -> PyResult<Self> {
+        board.parse_san(san)
+    }
 
     /// Create a new move from a UCI string (e.g. "e2e4").
     ///
@@ -80,7 +112,7 @@ impl PyMove {
     /// ```
     #[staticmethod]
     #[inline]
-    fn from_uci(uci: &str) -> PyResult<Self> {
+    pub(crate) fn from_uci(uci: &str) -> PyResult<Self> {
         // Parse the move using the chess crate
         let uci = uci.to_lowercase();
         chess::ChessMove::from_str(&uci)
@@ -118,18 +150,46 @@ impl PyMove {
     /// ```python
     /// >>> move = rust_chess.Move(rust_chess.A2, rust_chess.A4)
     /// >>> move
-    /// Move(e2, e4, None)
+    /// Move(a2, a4, None)
+    /// >>> rust_chess.Move("a7a8q")
+    /// Move(a7, a8, QUEEN)
     /// ```
     #[inline]
     fn __repr__(&self) -> String {
+        // Get the name of the promotion piece, otherwise None
+        let promotion = match self.0.get_promotion() {
+            None => "None",
+            Some(chess::Piece::Pawn) => "PAWN",
+            Some(chess::Piece::Knight) => "KNIGHT",
+            Some(chess::Piece::Bishop) => "BISHOP",
+            Some(chess::Piece::Rook) => "ROOK",
+            Some(chess::Piece::Queen) => "QUEEN",
+            Some(chess::Piece::King) => "KING",
+        };
+
         format!(
-            "Move({}, {}, {:?})",
+            "Move({}, {}, {})",
             self.0.get_source(),
             self.0.get_dest(),
-            self.0.get_promotion() // FIXME: Don't output Some(<PyPiece>)
+            promotion
         )
     }
 
+    /// Reduce the move to its UCI string for pickling.
+    ///
+    /// ```python
+    /// >>> import pickle
+    /// >>> pickle.loads(pickle.dumps(rust_chess.Move("a7a8q")))
+    /// Move(a7, a8, QUEEN)
+    /// ```
+    #[inline]
+    fn __reduce__(&self) -> PyResult<(Bound<'_, PyType>, Bound<'_, PyTuple>)> {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        Ok((py.get_type::<PyMove>(), PyTuple::new(py, [self.get_uci()])?))
+    }
+
     /// Get the source square of the move.
     ///
     /// ```python
@@ -174,14 +234,189 @@ impl PyMove {
     fn get_promotion(&self) -> Option<PyPieceType> {
         self.0.get_promotion().map(PyPieceType)
     }
+
+    /// Get the index of the move in the `AlphaZero` policy encoding (73 planes of 8 * 8 squares = 4672 indices).
+    /// Moves are encoded from the view of the side to move of the board, so for black the ranks are flipped
+    /// (a8 becomes a1, and moves towards rank 1 are north).
+    /// The index is `plane * 64 + source`, where `source` is the index of the flipped source square (a1 = 0, h8 = 63) and the planes are:
+    /// - 0-55: queen-like moves, as `direction * 7 + (distance - 1)` with directions N, NE, E, SE, S, SW, W, NW
+    /// - 56-63: knight moves, with offsets (rank, file) (2, 1), (1, 2), (-1, 2), (-2, 1), (-2, -1), (-1, -2), (1, -2), (2, -1)
+    /// - 64-72: underpromotions, as `piece * 3 + (file change + 1)` with pieces knight, bishop, rook
+    ///
+    /// Queen promotions are encoded as queen-like moves.
+    /// Raises a `ValueError` if the move can't be encoded (e.g. not a queen-like or knight move).
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> rust_chess.Move("e2e4").to_policy_index(board)
+    /// 76
+    /// >>> rust_chess.Move("g1f3").to_policy_index(board)
+    /// 4038
+    /// >>> rust_chess.Move("a7a8n").to_policy_index(board)
+    /// 4208
+    /// >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+    /// >>> rust_chess.Move("e7e5").to_policy_index(board) # The same as e2e4 for white
+    /// 76
+    /// >>> rust_chess.Move("a2a1n").to_policy_index(board) # The same as a7a8n for white
+    /// 4208
+    /// ```
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)] // Indices are less than 64
+    fn to_policy_index(&self, board: &PyBoard) -> PyResult<usize> {
+        let source = self.0.get_source();
+        let dest = self.0.get_dest();
+        let flip = board.board.side_to_move() == chess::Color::Black;
+
+        // Flip the ranks for black, so moves are relative to the side to move
+        let mut rank_change = dest.get_rank().to_index() as i8 - source.get_rank().to_index() as i8;
+        if flip {
+            rank_change = -rank_change;
+        }
+        let file_change = dest.get_file().to_index() as i8 - source.get_file().to_index() as i8;
+
+        let plane = if let Some(piece_index) = self
+            .0
+            .get_promotion()
+            .and_then(|promotion| UNDERPROMOTIONS.iter().position(|&piece| piece == promotion))
+        {
+            if rank_change.abs() != 1 || file_change.abs() > 1 {
+                return Err(PyValueError::new_err("Move can't be encoded as a policy index"));
+            }
+            64 + piece_index * 3 + (file_change + 1) as usize
+        } else if let Some(knight_index) = KNIGHT_OFFSETS.iter().position(|&offset| offset == (rank_change, file_change)) {
+            56 + knight_index
+        } else {
+            let distance = rank_change.abs().max(file_change.abs());
+            let is_line = rank_change == 0 || file_change == 0 || rank_change.abs() == file_change.abs();
+            let direction = QUEEN_DIRECTIONS
+                .iter()
+                .position(|&direction| direction == (rank_change.signum(), file_change.signum()))
+                .filter(|_| is_line)
+                .ok_or_else(|| PyValueError::new_err("Move can't be encoded as a policy index"))?;
+            direction * 7 + (distance - 1) as usize
+        };
+
+        Ok(plane * 64 + relative_square_index(source.to_index(), flip))
+    }
+
+    /// Create a new move from its index in the `AlphaZero` policy encoding (see `to_policy_index`).
+    /// The board is used for the side to move (the ranks are flipped for black) and to detect queen promotions.
+    /// Raises a `ValueError` if the index is out of range or the move would leave the board.
+    /// Doesn't check legality.
+    ///
+    /// ```python
+    /// >>> board = rust_chess.Board()
+    /// >>> rust_chess.Move.from_policy_index(76, board)
+    /// Move(e2, e4, None)
+    /// >>> board = rust_chess.Board("8/P6k/8/8/8/8/8/K7 w - - 0 1")
+    /// >>> rust_chess.Move.from_policy_index(rust_chess.Move("a7a8n").to_policy_index(board), board)
+    /// Move(a7, a8, KNIGHT)
+    /// >>> rust_chess.Move.from_policy_index(rust_chess.Move("a7a8q").to_policy_index(board), board)
+    /// Move(a7, a8, QUEEN)
+    /// >>> board = rust_chess.Board("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+    /// >>> rust_chess.Move.from_policy_index(76, board)
+    /// Move(e7, e5, None)
+    /// >>> moves = board.get_legal_moves()
+    /// >>> [rust_chess.Move.from_policy_index(move.to_policy_index(board), board) for move in moves] == moves
+    /// True
+    /// >>> board = rust_chess.Board("8/8/8/8/8/8/p6K/k7 b - - 0 1")
+    /// >>> rust_chess.Move.from_policy_index(rust_chess.Move("a2a1n").to_policy_index(board), board)
+    /// Move(a2, a1, KNIGHT)
+    /// >>> rust_chess.Move.from_policy_index(rust_chess.Move("a2a1q").to_policy_index(board), board)
+    /// Move(a2, a1, QUEEN)
+    /// ```
+    #[staticmethod]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)] // Indices are less than 64
+    fn from_policy_index(index: usize, board: &PyBoard) -> PyResult<Self> {
+        if index >= POLICY_PLANES * 64 {
+            return Err(PyValueError::new_err(format!(
+                "Policy index must be less than {}",
+                POLICY_PLANES * 64
+            )));
+        }
+        let flip = board.board.side_to_move() == chess::Color::Black;
+        let source = chess::ALL_SQUARES[relative_square_index(index % 64, flip)];
+        let plane = index / 64;
+
+        let (mut rank_change, file_change, mut promotion) = match plane {
+            0..56 => {
+                let (rank_direction, file_direction) = QUEEN_DIRECTIONS[plane / 7];
+                let distance = (plane % 7 + 1) as i8;
+                (rank_direction * distance, file_direction * distance, None)
+            }
+            56..64 => {
+                let (rank_offset, file_offset) = KNIGHT_OFFSETS[plane - 56];
+                (rank_offset, file_offset, None)
+            }
+            _ => {
+                // Underpromotions move one rank north (towards the opponent of the side to move)
+                let file_change = ((plane - 64) % 3) as i8 - 1;
+                (1, file_change, Some(UNDERPROMOTIONS[(plane - 64) / 3]))
+            }
+        };
+
+        // Flip the ranks back for black
+        if flip {
+            rank_change = -rank_change;
+        }
+
+        let rank_index = source.get_rank().to_index() as i8 + rank_change;
+        let file_index = source.get_file().to_index() as i8 + file_change;
+        if !(0..8).contains(&rank_index) || !(0..8).contains(&file_index) {
+            return Err(PyValueError::new_err("Policy index moves off the board"));
+        }
+        let dest = chess::Square::make_square(
+            chess::Rank::from_index(rank_index as usize),
+            chess::File::from_index(file_index as usize),
+        );
+
+        // Pawn moves onto the first or last rank without an underpromotion are queen promotions
+        if promotion.is_none()
+            && board.board.piece_on(source) == Some(chess::Piece::Pawn)
+            && (rank_index == 0 || rank_index == 7)
+        {
+            promotion = Some(chess::Piece::Queen);
+        }
+
+        Ok(PyMove(chess::ChessMove::new(source, dest, promotion)))
+    }
+
+    /// Check if the move is a promotion.
+    ///
+    /// ```python
+    /// >>> rust_chess.Move("a7a8q").is_promotion()
+    /// True
+    /// >>> rust_chess.Move("e2e4").is_promotion()
+    /// False
+    /// ```
+    #[inline]
+    fn is_promotion(&self) -> bool {
+        self.0.get_promotion().is_some()
+    }
 }
 
 /// Move iterator class for generating legal moves.
 /// Not intended for direct use.
 /// Use the `Board` class methods for generating moves.
+/// Generators are stateful one-shot iterators, so each move is only generated once.
+/// Copy a generator to iterate over its remaining moves more than once.
 #[gen_stub_pyclass]
 #[pyclass(name = "MoveGenerator")]
-pub(crate) struct PyMoveGenerator(pub(crate) chess::MoveGen);
+pub(crate) struct PyMoveGenerator {
+    move_gen: chess::MoveGen,
+    // The chess crate's move generator can't be cloned, so keep the board to create copies from
+    board: chess::Board,
+    // The chess crate doesn't expose the iterator mask, so keep track of it here
+    iterator_mask: chess::BitBoard,
+    // Promotions onto the same square are generated in groups of four (one per promotion piece).
+    // The rest of a group is taken from the chess crate at once and stored here (in reverse order),
+    // so the chess crate's generator is never partway through a promotion square.
+    pending_promotions: Vec<chess::ChessMove>,
+    // Moves generated or removed and masks removed, replayed onto a new generator when copying
+    removed_moves: Vec<chess::ChessMove>,
+    removed_mask: chess::BitBoard,
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -195,7 +430,97 @@ impl PyMoveGenerator {
     /// Get the next move in the generator
     #[inline]
     pub(crate) fn __next__(&mut self) -> Option<PyMove> {
-        self.0.next().map(PyMove)
+        // Finish a promotion group first if its square is in the iterator mask
+        if self.pending_promotions_masked() {
+            return self.pending_promotions.pop().map(PyMove);
+        }
+
+        let chess_move = self.move_gen.next()?;
+        self.removed_moves.push(chess_move);
+
+        // Take the rest of the promotion group, so the chess crate's generator moves on to the next square
+        if chess_move.get_promotion().is_some() {
+            self.pending_promotions = self.move_gen.by_ref().take(3).collect();
+            self.pending_promotions.reverse();
+        }
+
+        Some(PyMove(chess_move))
+    }
+
+    /// Get the number of moves remaining in the generator.
+    /// Only counts moves allowed by the current iterator mask.
+    ///
+    /// ```python
+    /// >>> generator = rust_chess.Board().generate_legal_moves()
+    /// >>> len(generator)
+    /// 20
+    /// >>> _ = next(generator), next(generator)
+    /// >>> len(generator)
+    /// 18
+    /// >>> board = rust_chess.Board("8/P6k/8/8/8/8/8/K7 w - - 0 1")
+    /// >>> next(board.generate_legal_moves())
+    /// Move(a7, a8, QUEEN)
+    /// >>> len(board.generate_legal_captures())
+    /// 0
+    /// >>> len(board.generate_legal_moves())
+    /// 6
+    /// ```
+    #[inline]
+    pub(crate) fn __len__(&mut self) -> usize {
+        // The chess crate only counts correctly before iteration starts,
+        // so reapply the iterator mask to move the remaining moves to the front
+        self.move_gen.set_iterator_mask(self.iterator_mask);
+
+        // Add the rest of a partially generated promotion group
+        let pending = if self.pending_promotions_masked() { self.pending_promotions.len() } else { 0 };
+        self.move_gen.len() + pending
+    }
+
+    /// Create an independent copy of the generator (used by `copy.copy`).
+    /// The copy is a new generator for the same board with the same iterator mask and remaining moves,
+    /// so it generates the same moves as the original.
+    ///
+    /// ```python
+    /// >>> import copy
+    /// >>> board = rust_chess.Board("r3k3/8/8/8/8/8/3n4/R3K3 w - - 0 1")
+    /// >>> generator = board.generate_legal_captures()
+    /// >>> next(generator)
+    /// Move(a1, a8, None)
+    /// >>> generator_copy = copy.copy(generator)
+    /// >>> list(generator), list(generator_copy)
+    /// ([Move(e1, d2, None)], [Move(e1, d2, None)])
+    /// >>> generator = rust_chess.Board("8/P6k/8/8/8/8/8/K7 w - - 0 1").generate_legal_moves()
+    /// >>> next(generator)
+    /// Move(a7, a8, QUEEN)
+    /// >>> list(copy.copy(generator))[:3]
+    /// [Move(a7, a8, KNIGHT), Move(a7, a8, ROOK), Move(a7, a8, BISHOP)]
+    /// ```
+    #[inline]
+    fn __copy__(&self) -> Self {
+        // Remove the moves that are no longer in this generator from a new generator for the same board
+        let mut move_gen = chess::MoveGen::new_legal(&self.board);
+        move_gen.remove_mask(self.removed_mask);
+        for &chess_move in &self.removed_moves {
+            move_gen.remove_move(chess_move);
+        }
+        move_gen.set_iterator_mask(self.iterator_mask);
+
+        PyMoveGenerator {
+            move_gen,
+            board: self.board,
+            iterator_mask: self.iterator_mask,
+            pending_promotions: self.pending_promotions.clone(),
+            removed_moves: self.removed_moves.clone(),
+            removed_mask: self.removed_mask,
+        }
+    }
+
+    /// Create an independent copy of the generator (used by `copy.deepcopy`).
+    /// Same as `__copy__`.
+    ///
+    #[inline]
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.__copy__()
     }
 
     /// Get the type of the move generator
@@ -204,3 +529,61 @@ impl PyMoveGenerator {
         "MoveGenerator()".to_string()
     }
 }
+
+impl PyMoveGenerator {
+    /// Create a new move generator for the legal moves of a board
+    #[inline]
+    pub(crate) fn new(board: &chess::Board) -> Self {
+        PyMoveGenerator {
+            move_gen: chess::MoveGen::new_legal(board),
+            board: *board,
+            iterator_mask: !chess::EMPTY,
+            pending_promotions: Vec::new(),
+            removed_moves: Vec::new(),
+            removed_mask: chess::EMPTY,
+        }
+    }
+
+    /// Set the iterator mask, only generating moves onto squares in the mask
+    #[inline]
+    pub(crate) fn set_iterator_mask(&mut self, mask: chess::BitBoard) {
+        self.move_gen.set_iterator_mask(mask);
+        self.iterator_mask = mask;
+    }
+
+    /// Remove a move from the generator.
+    /// Removing a promotion removes all of the promotions onto the same square (like the chess crate).
+    #[inline]
+    pub(crate) fn remove_move(&mut self, chess_move: chess::ChessMove) {
+        self.pending_promotions.retain(|pending| {
+            pending.get_source() != chess_move.get_source() || pending.get_dest() != chess_move.get_dest()
+        });
+        self.move_gen.remove_move(chess_move);
+        self.removed_moves.push(chess_move);
+    }
+
+    /// Remove the moves onto squares in a mask from the generator
+    #[inline]
+    pub(crate) fn remove_mask(&mut self, mask: chess::BitBoard) {
+        self.pending_promotions
+            .retain(|pending| mask & chess::BitBoard::from_square(pending.get_dest()) == chess::EMPTY);
+        self.move_gen.remove_mask(mask);
+        self.removed_mask |= mask;
+    }
+
+    /// Check if there are pending promotions onto a square in the iterator mask
+    #[inline]
+    fn pending_promotions_masked(&self) -> bool {
+        self.pending_promotions
+            .last()
+            .is_some_and(|pending| self.iterator_mask & chess::BitBoard::from_square(pending.get_dest()) != chess::EMPTY)
+    }
+}
+
+/// Get the index of a square in the policy encoding, flipping the ranks if black is to move (e.g. a8 becomes a1).
+/// Flipping the rank of a square index (rank * 8 + file) is the same as toggling its rank bits.
+///
+#[inline]
+const fn relative_square_index(square_index: usize, flip: bool) -> usize {
+    if flip { square_index ^ 0b11_1000 } else { square_index }
+}
@@ -1,7 +1,7 @@
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
-use crate::types::color::{PyColor, WHITE};
+use crate::types::color::{PyColor, BLACK, WHITE};
 
 // Piece constants
 pub(crate) const PAWN: PyPieceType = PyPieceType(chess::Piece::Pawn);
@@ -12,6 +12,9 @@ pub(crate) const QUEEN: PyPieceType = PyPieceType(chess::Piece::Queen);
 pub(crate) const KING: PyPieceType = PyPieceType(chess::Piece::King);
 pub(crate) const PIECES: [PyPieceType; 6] = [PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING];
 
+// Default piece values in centipawns, indexed by piece index (PAWN to KING)
+pub(crate) const PIECE_VALUES: [i32; 6] = [100, 300, 300, 500, 900, 0];
+
 /// Piece type enum class.
 /// Represents the different types of chess pieces.
 /// Indexing starts at 0 (PAWN) and ends at 5 (KING).
@@ -42,6 +45,45 @@ pub(crate) struct PyPieceType(pub(crate) chess::Piece);
 #[gen_stub_pymethods]
 #[pymethods]
 impl PyPieceType {
+    /// Create a piece type from its index (0-5).
+    ///
+    /// ```python
+    /// >>> rust_chess.PieceType.from_index(4)
+    /// Q
+    /// >>> rust_chess.PieceType.from_index(4) == rust_chess.QUEEN
+    /// True
+    /// ```
+    #[staticmethod]
+    #[inline]
+    fn from_index(index: u8) -> PyResult<Self> {
+        PIECES
+            .get(usize::from(index))
+            .copied()
+            .ok_or_else(|| PyValueError::new_err("Piece type index must be between 0 and 5"))
+    }
+
+    /// Create a piece type from its letter (p, n, b, r, q, or k), case-insensitive.
+    ///
+    /// ```python
+    /// >>> rust_chess.PieceType.from_symbol("N") == rust_chess.KNIGHT
+    /// True
+    /// >>> rust_chess.PieceType.from_symbol("k")
+    /// K
+    /// ```
+    #[staticmethod]
+    #[inline]
+    pub(crate) fn from_symbol(symbol: &str) -> PyResult<Self> {
+        match symbol.to_lowercase().as_str() {
+            "p" => Ok(PAWN),
+            "n" => Ok(KNIGHT),
+            "b" => Ok(BISHOP),
+            "r" => Ok(ROOK),
+            "q" => Ok(QUEEN),
+            "k" => Ok(KING),
+            _ => Err(PyValueError::new_err(format!("Invalid piece symbol '{symbol}'"))),
+        }
+    }
+
     /// Get the index of the piece.
     /// Ranges from 0 (PAWN) to 5 (KING).
     ///
@@ -55,6 +97,32 @@ impl PyPieceType {
         self.0.to_index() as u8
     }
 
+    /// Get the hash of the piece type, which is its index (0-5).
+    /// Allows piece types to be used as dictionary keys and in sets.
+    ///
+    /// ```python
+    /// >>> hash(rust_chess.BISHOP) == hash(2)
+    /// True
+    /// >>> {rust_chess.QUEEN: 900}[rust_chess.QUEEN]
+    /// 900
+    /// ```
+    #[inline]
+    fn __hash__(&self) -> u64 {
+        u64::from(self.get_index())
+    }
+
+    /// Get the index of the piece as an integer.
+    /// Useful for indexing tables by piece type.
+    ///
+    /// ```python
+    /// >>> int(rust_chess.QUEEN)
+    /// 4
+    /// ```
+    #[inline]
+    fn __int__(&self) -> u8 {
+        self.get_index()
+    }
+
     /// Convert the piece to a string.
     /// Returns the capital piece type letter.
     ///
@@ -95,7 +163,7 @@ impl PyPieceType {
 
 /// Piece class.
 /// Represents a chess piece with a type and color.
-/// Uses the PieceType and Color classes.
+/// Uses the `PieceType` and `Color` classes.
 /// Supports comparison and equality.
 /// A white piece is considered less than a black piece of the same type.
 ///
@@ -124,6 +192,24 @@ impl PyPiece {
         PyPiece { piece_type, color }
     }
 
+    /// Create a piece from its FEN letter.
+    /// Uppercase letters are white pieces and lowercase letters are black pieces.
+    ///
+    /// ```python
+    /// >>> rust_chess.Piece.from_symbol("Q") == rust_chess.Piece(rust_chess.QUEEN, rust_chess.WHITE)
+    /// True
+    /// >>> rust_chess.Piece.from_symbol("q")
+    /// q
+    /// ```
+    #[staticmethod]
+    #[inline]
+    fn from_symbol(symbol: &str) -> PyResult<Self> {
+        let piece_type = PyPieceType::from_symbol(symbol)?;
+        let color = if symbol.chars().all(char::is_uppercase) { WHITE } else { BLACK };
+
+        Ok(PyPiece { piece_type, color })
+    }
+
     /// Get the index of the piece (0-5)
     #[inline]
     fn get_index(&self) -> u8 {
@@ -136,6 +222,24 @@ impl PyPiece {
         self.piece_type.get_string(self.color)
     }
 
+    /// Get the Unicode chess symbol of the piece.
+    ///
+    /// ```python
+    /// >>> rust_chess.Piece(rust_chess.KNIGHT, rust_chess.WHITE).unicode_symbol()
+    /// '♘'
+    /// >>> rust_chess.Piece(rust_chess.QUEEN, rust_chess.BLACK).unicode_symbol()
+    /// '♛'
+    /// ```
+    #[inline]
+    fn unicode_symbol(&self) -> String {
+        let symbols = if self.color == WHITE {
+            ['♙', '♘', '♗', '♖', '♕', '♔']
+        } else {
+            ['♟', '♞', '♝', '♜', '♛', '♚']
+        };
+        symbols[self.piece_type.0.to_index()].to_string()
+    }
+
     /// Convert the piece to a string
     #[inline]
     fn __str__(&self) -> String {
@@ -1,6 +1,11 @@
 use std::str::FromStr;
 
-use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::PyAny};
+use pyo3::{
+    basic::CompareOp,
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyAny, PyTuple, PyType},
+};
 use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
 
 use crate::types::{
@@ -36,7 +41,7 @@ use crate::types::{
 /// TODO
 /// ```
 #[gen_stub_pyclass]
-#[pyclass(name = "Square", frozen)]
+#[pyclass(name = "Square", module = "rust_chess", frozen)]
 #[derive(PartialEq, Ord, Eq, PartialOrd, Copy, Clone, Default, Hash)]
 pub(crate) struct PySquare(pub(crate) chess::Square);
 
@@ -155,6 +160,21 @@ impl PySquare {
         self.get_name()
     }
 
+    /// Reduce the square to its index for pickling.
+    ///
+    /// ```python
+    /// >>> import pickle
+    /// >>> pickle.loads(pickle.dumps(rust_chess.E4))
+    /// e4
+    /// ```
+    #[inline]
+    fn __reduce__(&self) -> PyResult<(Bound<'_, PyType>, Bound<'_, PyTuple>)> {
+        // We can assume the GIL is acquired, since this function is only called from Python
+        let py = unsafe { Python::assume_gil_acquired() };
+
+        Ok((py.get_type::<PySquare>(), PyTuple::new(py, [self.get_index()])?))
+    }
+
     /// Get the color of the square on the chessboard
     #[inline]
     pub(crate) fn get_color(&self) -> PyColor {
@@ -222,6 +242,20 @@ impl PySquare {
         })
     }
 
+    /// Get the hash of the square, which is its index (0-63).
+    /// Allows squares to be used as dictionary keys and in sets.
+    ///
+    /// ```python
+    /// >>> hash(rust_chess.E4) == hash(28)
+    /// True
+    /// >>> len({rust_chess.A1, rust_chess.Square("a1"), rust_chess.H8})
+    /// 2
+    /// ```
+    #[inline]
+    fn __hash__(&self) -> u64 {
+        u64::from(self.get_index())
+    }
+
     /// Get the rank of the square as an integer (0-7).
     ///
     /// ```python
@@ -289,4 +323,67 @@ impl PySquare {
     fn right(&self) -> Option<Self> {
         self.0.right().map(PySquare)
     }
+
+    /// Get the square with the rank flipped (e.g. a1 to a8).
+    /// Useful for looking up piece-square tables from black's perspective.
+    ///
+    /// ```python
+    /// >>> rust_chess.A1.mirror()
+    /// a8
+    /// >>> rust_chess.E4.mirror()
+    /// e5
+    /// ```
+    #[inline]
+    pub(crate) fn mirror(&self) -> Self {
+        PySquare(chess::Square::make_square(
+            chess::Rank::from_index(7 - self.0.get_rank().to_index()),
+            self.0.get_file(),
+        ))
+    }
+
+    /// Get the square with the file flipped (e.g. a1 to h1).
+    ///
+    /// ```python
+    /// >>> rust_chess.H1.mirror_horizontal()
+    /// a1
+    /// >>> rust_chess.C6.mirror_horizontal()
+    /// f6
+    /// ```
+    #[inline]
+    fn mirror_horizontal(&self) -> Self {
+        PySquare(chess::Square::make_square(
+            self.0.get_rank(),
+            chess::File::from_index(7 - self.0.get_file().to_index()),
+        ))
+    }
+
+    /// Get the Chebyshev (king move) distance to another square.
+    ///
+    /// ```python
+    /// >>> rust_chess.A1.distance(rust_chess.H8)
+    /// 7
+    /// >>> rust_chess.E4.distance(rust_chess.E5)
+    /// 1
+    /// ```
+    #[inline]
+    fn distance(&self, other: PySquare) -> u8 {
+        let file_distance = self.get_file().abs_diff(other.get_file());
+        let rank_distance = self.get_rank().abs_diff(other.get_rank());
+        file_distance.max(rank_distance)
+    }
+
+    /// Get the Manhattan distance (files plus ranks apart) to another square.
+    ///
+    /// ```python
+    /// >>> rust_chess.A1.manhattan_distance(rust_chess.H8)
+    /// 14
+    /// >>> rust_chess.E4.manhattan_distance(rust_chess.D5)
+    /// 2
+    /// ```
+    #[inline]
+    fn manhattan_distance(&self, other: PySquare) -> u8 {
+        let file_distance = self.get_file().abs_diff(other.get_file());
+        let rank_distance = self.get_rank().abs_diff(other.get_rank());
+        file_distance + rank_distance
+    }
 }